@@ -0,0 +1,92 @@
+//! Compares drawing a changing number the way the frame rate lessons used to,
+//! by rendering a new texture every frame, with drawing it from a
+//! `GlyphAtlas`. Run with `cargo bench --bench text`.
+//!
+//! Allocations are counted for Rust's heap; the surfaces and textures SDL
+//! makes for rendered text come on top of those.
+
+#[path = "../tests/common/counting.rs"]
+mod counting;
+
+use counting::{allocations, Counting};
+use lazyfoo::font::{init_ttf, load_font};
+use lazyfoo::{GlyphAtlas, LTexture, Result};
+use sdl2::pixels::Color;
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::ttf::Font;
+use sdl2::video::WindowContext;
+use std::env;
+use std::fmt::Write;
+use std::time::Instant;
+
+const FRAMES: u32 = 2000;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+//The text a frame rate counter shows on `frame`
+fn fps_text(frame: u32) -> f32 {
+    60.0 - (frame % 97) as f32 / 13.0
+}
+
+fn measure(name: &str, mut frame: impl FnMut(u32) -> Result<()>) -> Result<()> {
+    //Warm up first, so one-time setup isn't counted
+    frame(0)?;
+
+    let before = allocations();
+    let start = Instant::now();
+    for i in 1..=FRAMES {
+        frame(i)?;
+    }
+    let elapsed = start.elapsed();
+    let allocations = allocations() - before;
+
+    println!(
+        "{name:<14} {:>6.2} allocations {:>10.2?} per frame",
+        allocations as f64 / FRAMES as f64,
+        elapsed / FRAMES,
+    );
+    Ok(())
+}
+
+fn rendered_text(
+    canvas: &mut WindowCanvas,
+    texture_creator: &TextureCreator<WindowContext>,
+    font: &Font,
+) -> Result<()> {
+    measure("rendered text", |frame| {
+        let text = format!("{:.2}", fps_text(frame));
+        let texture =
+            LTexture::load_from_rendered_text(texture_creator, font, &text, Color::RGB(0, 0, 0))?;
+        texture.render(canvas, 0, 0, None)
+    })
+}
+
+fn glyph_atlas(canvas: &mut WindowCanvas, atlas: &mut GlyphAtlas) -> Result<()> {
+    atlas.prepare("0123456789.")?;
+    let mut text = String::new();
+    measure("glyph atlas", |frame| {
+        text.clear();
+        let _ = write!(text, "{:.2}", fps_text(frame));
+        atlas.prepare(&text)?;
+        atlas.render(canvas, 0, 0, &text)
+    })
+}
+
+fn main() -> Result<()> {
+    env::set_current_dir(env!("CARGO_MANIFEST_DIR")).expect("crate root is accessible");
+    sdl2::hint::set("SDL_VIDEODRIVER", "dummy");
+
+    let sdl_context = sdl2::init().expect("SDL starts");
+    let video = sdl_context.video().expect("video starts");
+    let mut canvas = lazyfoo::app::create_canvas(&video, "text", true)?;
+    let texture_creator = canvas.texture_creator();
+    let ttf_context = init_ttf()?;
+
+    let font = load_font(&ttf_context, "resources/lazy.ttf", 32)?;
+    rendered_text(&mut canvas, &texture_creator, &font)?;
+
+    let mut atlas = GlyphAtlas::new(&texture_creator, font)?;
+    atlas.set_color(0, 0, 0);
+    glyph_atlas(&mut canvas, &mut atlas)
+}
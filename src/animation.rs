@@ -0,0 +1,198 @@
+use crate::error::Result;
+use crate::texture::LTexture;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+/// What an animation does after its last frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Playback {
+    /// Starts over from the first frame.
+    Loop,
+    /// Plays backwards to the first frame, then forwards again.
+    PingPong,
+    /// Stops on the last frame.
+    Once,
+}
+
+/// One clip of a sprite sheet and how long it stays on screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnimationFrame {
+    pub clip: Rect,
+    pub duration: Duration,
+}
+
+/// Steps through sprite sheet clips by elapsed time rather than by rendered frame,
+/// so it runs at the same speed whatever the frame rate.
+///
+/// Callbacks added with [`on_finished`](Self::on_finished) run each time a cycle
+/// completes: every wrap of a `Loop`, every return to the first frame of a
+/// `PingPong`, and once when a `Once` animation has shown its last frame.
+pub struct Animation {
+    frames: Vec<AnimationFrame>,
+    playback: Playback,
+    time_scale: f64,
+    index: usize,
+    //Time spent on the current frame
+    elapsed: Duration,
+    reversing: bool,
+    finished: bool,
+    on_finished: Vec<Box<dyn FnMut()>>,
+}
+
+impl Animation {
+    /// Panics if `frames` is empty.
+    pub fn new(frames: impl IntoIterator<Item = AnimationFrame>, playback: Playback) -> Self {
+        let frames: Vec<_> = frames.into_iter().collect();
+        assert!(!frames.is_empty(), "an animation needs at least one frame");
+        Self {
+            frames,
+            playback,
+            time_scale: 1.0,
+            index: 0,
+            elapsed: Duration::ZERO,
+            reversing: false,
+            finished: false,
+            on_finished: Vec::new(),
+        }
+    }
+
+    /// Shows each of `clips` for `frame_time`. Panics if `clips` is empty.
+    pub fn uniform(
+        clips: impl IntoIterator<Item = Rect>,
+        frame_time: Duration,
+        playback: Playback,
+    ) -> Self {
+        let frames = clips.into_iter().map(|clip| AnimationFrame {
+            clip,
+            duration: frame_time,
+        });
+        Self::new(frames, playback)
+    }
+
+    pub fn on_finished(&mut self, callback: impl FnMut() + 'static) {
+        self.on_finished.push(Box::new(callback));
+    }
+
+    pub fn playback(&self) -> Playback {
+        self.playback
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Speeds the animation up or slows it down; 0 freezes it.
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    pub fn frame_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn current_clip(&self) -> Rect {
+        self.frames[self.index].clip
+    }
+
+    /// Whether a `Once` animation has shown its last frame for its full duration.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Goes back to the first frame and plays again.
+    pub fn restart(&mut self) {
+        self.index = 0;
+        self.elapsed = Duration::ZERO;
+        self.reversing = false;
+        self.finished = false;
+    }
+
+    pub fn advance(&mut self, dt: Duration) {
+        if self.finished || self.cycle_is_empty() {
+            return;
+        }
+
+        self.elapsed += dt.mul_f64(self.time_scale);
+        while self.elapsed >= self.frames[self.index].duration {
+            self.elapsed -= self.frames[self.index].duration;
+            let completed = self.step();
+            if completed {
+                self.on_finished.iter_mut().for_each(|callback| callback());
+            }
+            if self.finished {
+                self.elapsed = Duration::ZERO;
+                break;
+            }
+        }
+    }
+
+    pub fn hash_state(&self, state: &mut dyn Hasher) {
+        state.write_usize(self.index);
+        state.write_u128(self.elapsed.as_nanos());
+        state.write_u8(self.reversing as u8 | (self.finished as u8) << 1);
+    }
+
+    //A repeating animation whose frames all take no time would never catch up
+    fn cycle_is_empty(&self) -> bool {
+        self.playback != Playback::Once && self.frames.iter().all(|f| f.duration.is_zero())
+    }
+
+    //Moves to the next frame and returns whether that completed a cycle
+    fn step(&mut self) -> bool {
+        let last = self.frames.len() - 1;
+        match self.playback {
+            Playback::Loop => {
+                self.index = if self.index == last {
+                    0
+                } else {
+                    self.index + 1
+                };
+                self.index == 0
+            }
+            Playback::PingPong if last == 0 => true,
+            Playback::PingPong => {
+                if self.reversing {
+                    self.index -= 1;
+                    self.reversing = self.index != 0;
+                    self.index == 0
+                } else {
+                    self.index += 1;
+                    self.reversing = self.index == last;
+                    false
+                }
+            }
+            Playback::Once if self.index == last => {
+                self.finished = true;
+                true
+            }
+            Playback::Once => {
+                self.index += 1;
+                false
+            }
+        }
+    }
+}
+
+/// An [`Animation`] drawn at a position. The texture is passed in when rendering,
+/// so several sprites can share one sprite sheet while animating independently.
+pub struct AnimatedSprite {
+    pub animation: Animation,
+    pub x: i32,
+    pub y: i32,
+}
+
+impl AnimatedSprite {
+    pub fn new(animation: Animation, x: i32, y: i32) -> Self {
+        Self { animation, x, y }
+    }
+
+    pub fn update(&mut self, dt: Duration) {
+        self.animation.advance(dt);
+    }
+
+    pub fn render(&self, texture: &LTexture, canvas: &mut WindowCanvas) -> Result<()> {
+        texture.render(canvas, self.x, self.y, Some(self.animation.current_clip()))
+    }
+}
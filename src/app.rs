@@ -0,0 +1,382 @@
+use crate::assets::AssetCache;
+use crate::capture::{self, FrameCapture, SCREENSHOT_DIR, SCREENSHOT_KEY};
+use crate::clock::SdlClock;
+use crate::controller::Controllers;
+use crate::error::{Error, Result};
+use crate::font::init_ttf;
+use crate::input::{Bindings, Input, DEFAULT_BINDINGS};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::menu::Menu;
+use crate::replay::{self, Session};
+use crate::timer::LTimer;
+use crate::timestep::FixedTimestep;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::render::WindowCanvas;
+use sdl2::{TimerSubsystem, VideoSubsystem};
+use std::error::Error as _;
+use std::thread;
+use std::time::Duration;
+
+const MENU_TITLE: &str = "SDL Tutorials";
+const MENU_NAME: &str = "menu";
+
+//How often resource files are checked for changes
+const RELOAD_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    //Escape was pressed: leave the lesson
+    Back,
+    //The window was closed
+    Quit,
+}
+
+pub fn create_canvas(video: &VideoSubsystem, title: &str, software: bool) -> Result<WindowCanvas> {
+    let window = video
+        .window(title, SCREEN_WIDTH, SCREEN_HEIGHT)
+        .position_centered()
+        .build()
+        .map_err(|e| Error::init("window", e))?;
+
+    let mut builder = window.into_canvas();
+    if software {
+        builder = builder.software();
+    }
+    builder.build().map_err(|e| Error::init("canvas", e))
+}
+
+/// Opens a window and runs `info` until the window is closed or Escape is
+/// pressed, or until a replayed recording runs out. Frames are saved to
+/// `capture` as they are shown.
+pub fn run(
+    info: &LessonInfo,
+    mut session: Session,
+    mut capture: Option<FrameCapture>,
+) -> Result<()> {
+    let sdl_context = sdl2::init().map_err(|e| Error::init("sdl", e))?;
+    let video_subsystem = sdl_context.video().map_err(|e| Error::init("video", e))?;
+    let mut canvas = create_canvas(&video_subsystem, info.title, false)?;
+
+    let texture_creator = canvas.texture_creator();
+    let ttf_context = init_ttf()?;
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS)?);
+    let ctx = Context {
+        texture_creator: &texture_creator,
+        ttf_context: &ttf_context,
+        input: &input,
+        assets: AssetCache::new(&texture_creator, &ttf_context),
+        video: video_subsystem,
+        timer: sdl_context.timer().map_err(|e| Error::init("timer", e))?,
+    };
+    let mut lesson = (info.create)(&ctx)?;
+    let mut timestep = FixedTimestep::default();
+    let mut clock = FrameClock::new(ctx.timer.clone());
+    let mut hot_reload = HotReload::default();
+
+    let mut controllers = Controllers::new(
+        sdl_context
+            .game_controller()
+            .map_err(|e| Error::init("game controller", e))?,
+    );
+
+    let mut event_pump = sdl_context
+        .event_pump()
+        .map_err(|e| Error::init("event pump", e))?;
+    for index in 0.. {
+        let live: Vec<Event> = event_pump.poll_iter().collect();
+        let (events, elapsed) = match &session {
+            Session::Live => {
+                track_controllers(&mut controllers, &live);
+                (live, clock.elapsed())
+            }
+            //Keep only what the recording can reproduce
+            Session::Record(_) => {
+                track_controllers(&mut controllers, &live);
+                let events = live.into_iter().filter(replay::is_recordable).collect();
+                (events, clock.elapsed())
+            }
+            //Closing the window still ends a replay
+            Session::Replay(_) if live.iter().any(|e| matches!(e, Event::Quit { .. })) => break,
+            Session::Replay(recording) => {
+                let Some(recorded) = recording.frames.get(index) else {
+                    println!("replay finished: {index} frames matched");
+                    break;
+                };
+                //Play back at the recorded speed
+                if let Some(rest) = recorded.elapsed.checked_sub(clock.elapsed()) {
+                    thread::sleep(rest);
+                    clock.elapsed();
+                }
+                (recorded.events.clone(), recorded.elapsed)
+            }
+        };
+
+        hot_reload.poll(&ctx.assets, elapsed);
+        let flow = frame(
+            lesson.as_mut(),
+            &ctx,
+            &mut canvas,
+            events.iter().cloned(),
+            &mut timestep,
+            elapsed,
+        )?;
+        let checksum = replay::checksum(lesson.as_ref());
+        match &mut session {
+            Session::Live => {}
+            Session::Record(recorder) => recorder.write_frame(elapsed, &events, checksum)?,
+            Session::Replay(recording) => {
+                if recording.frames[index].checksum != checksum {
+                    return Err(Error::Diverged { frame: index });
+                }
+            }
+        }
+
+        if flow != Flow::Continue {
+            break;
+        }
+        if wants_screenshot(&events) {
+            save_screenshot(&canvas, info.name);
+        }
+        if let Some(frames) = capture.as_mut() {
+            frames.frame(&canvas)?;
+            if frames.is_finished() {
+                finish_capture(capture.take())?;
+            }
+        }
+        canvas.present();
+    }
+
+    finish_capture(capture)
+}
+
+/// Opens a single window showing a menu of `lessons`. Escape goes from a
+/// lesson back to the menu, and from the menu closes the window.
+pub fn launch(lessons: &'static [&'static LessonInfo]) -> Result<()> {
+    let sdl_context = sdl2::init().map_err(|e| Error::init("sdl", e))?;
+    let video_subsystem = sdl_context.video().map_err(|e| Error::init("video", e))?;
+    let mut canvas = create_canvas(&video_subsystem, MENU_TITLE, false)?;
+
+    let texture_creator = canvas.texture_creator();
+    let ttf_context = init_ttf()?;
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS)?);
+    let ctx = Context {
+        texture_creator: &texture_creator,
+        ttf_context: &ttf_context,
+        input: &input,
+        assets: AssetCache::new(&texture_creator, &ttf_context),
+        video: video_subsystem,
+        timer: sdl_context.timer().map_err(|e| Error::init("timer", e))?,
+    };
+    let mut menu = Menu::new(&ctx, lessons)?;
+    let mut current: Option<Box<dyn Lesson<'_> + '_>> = None;
+    //Names screenshots after what is on screen
+    let mut shown = MENU_NAME;
+    let mut timestep = FixedTimestep::default();
+    let mut clock = FrameClock::new(ctx.timer.clone());
+    let mut hot_reload = HotReload::default();
+
+    let mut controllers = Controllers::new(
+        sdl_context
+            .game_controller()
+            .map_err(|e| Error::init("game controller", e))?,
+    );
+
+    let mut event_pump = sdl_context
+        .event_pump()
+        .map_err(|e| Error::init("event pump", e))?;
+    loop {
+        let events: Vec<Event> = event_pump.poll_iter().collect();
+        track_controllers(&mut controllers, &events);
+        let screenshot = wants_screenshot(&events);
+        let elapsed = clock.elapsed();
+        hot_reload.poll(&ctx.assets, elapsed);
+        if let Some(lesson) = current.as_mut() {
+            match frame(
+                lesson.as_mut(),
+                &ctx,
+                &mut canvas,
+                events,
+                &mut timestep,
+                elapsed,
+            )? {
+                Flow::Continue => {}
+                Flow::Back => {
+                    current = None;
+                    shown = MENU_NAME;
+                    input.reset();
+                    ctx.assets.unload_unused();
+                    set_title(&mut canvas, MENU_TITLE);
+                    continue;
+                }
+                Flow::Quit => break,
+            }
+        } else {
+            let mut picked = None;
+            for event in events {
+                match event {
+                    Event::Quit { .. }
+                    | Event::KeyDown {
+                        keycode: Some(Keycode::Escape),
+                        ..
+                    } => return Ok(()),
+                    _ => picked = picked.or(menu.handle_event(&event)),
+                }
+            }
+
+            if let Some(info) = picked {
+                //A lesson that fails to start (e.g. no audio device) leaves the menu open
+                match (info.create)(&ctx) {
+                    Ok(lesson) => {
+                        current = Some(lesson);
+                        shown = info.name;
+                        timestep.reset();
+                        set_title(&mut canvas, info.title);
+                        continue;
+                    }
+                    Err(e) => eprintln!("could not start {}: {e}", info.title),
+                }
+            }
+            menu.render(&mut canvas)?;
+        }
+        if screenshot {
+            save_screenshot(&canvas, shown);
+        }
+        canvas.present();
+    }
+
+    Ok(())
+}
+
+fn wants_screenshot(events: &[Event]) -> bool {
+    events.iter().any(|event| {
+        matches!(
+            event,
+            Event::KeyDown {
+                keycode: Some(SCREENSHOT_KEY),
+                repeat: false,
+                ..
+            }
+        )
+    })
+}
+
+//A screenshot that can't be saved is reported and otherwise ignored
+fn save_screenshot(canvas: &WindowCanvas, name: &str) {
+    match capture::screenshot(canvas, SCREENSHOT_DIR, name) {
+        Ok(path) => println!("saved {}", path.display()),
+        Err(e) => match e.source() {
+            Some(source) => eprintln!("{e} ({source})"),
+            None => eprintln!("{e}"),
+        },
+    }
+}
+
+/// Finishes writing `capture`, if there is one, and reports what it saved.
+pub fn finish_capture(capture: Option<FrameCapture>) -> Result<()> {
+    if let Some(capture) = capture {
+        let path = capture.path().to_path_buf();
+        let frames = capture.finish()?;
+        println!("captured {frames} frames to {}", path.display());
+    }
+    Ok(())
+}
+
+//A pad that fails to open is reported and otherwise ignored
+fn track_controllers(controllers: &mut Controllers, events: &[Event]) {
+    for event in events {
+        if let Err(e) = controllers.handle_event(event) {
+            eprintln!("{e}");
+        }
+    }
+}
+
+fn set_title(canvas: &mut WindowCanvas, title: &str) {
+    //Titles are static strings without interior nul bytes
+    let _ = canvas.window_mut().set_title(title);
+}
+
+/// Feeds `events` to the lesson and `ctx.input`, runs the ticks `elapsed` adds up to, then
+/// updates and renders it without presenting.
+pub fn frame<'a>(
+    lesson: &mut (dyn Lesson<'a> + 'a),
+    ctx: &Context<'a>,
+    canvas: &mut WindowCanvas,
+    events: impl IntoIterator<Item = Event>,
+    timestep: &mut FixedTimestep,
+    elapsed: Duration,
+) -> Result<Flow> {
+    for event in events {
+        match event {
+            Event::Quit { .. } => return Ok(Flow::Quit),
+            Event::KeyDown {
+                keycode: Some(Keycode::Escape),
+                ..
+            } => return Ok(Flow::Back),
+            _ => {
+                ctx.input.handle_event(&event);
+                lesson.handle_event(ctx, &event)?;
+            }
+        }
+    }
+
+    for _ in 0..timestep.advance(elapsed) {
+        lesson.tick(ctx, timestep.step())?;
+    }
+    lesson.update(ctx)?;
+    ctx.input.end_frame();
+    lesson.render(canvas, timestep.alpha())?;
+    Ok(Flow::Continue)
+}
+
+//Reloads assets whose files changed every RELOAD_INTERVAL, logging each one
+#[derive(Default)]
+struct HotReload {
+    since_poll: Duration,
+}
+
+impl HotReload {
+    fn poll(&mut self, assets: &AssetCache, elapsed: Duration) {
+        self.since_poll += elapsed;
+        if self.since_poll < RELOAD_INTERVAL {
+            return;
+        }
+        self.since_poll = Duration::ZERO;
+
+        for reload in assets.reload_changed() {
+            match reload.result {
+                Ok(()) => println!("reloaded {}", reload.path.display()),
+                Err(e) => match e.source() {
+                    Some(source) => eprintln!("{e} ({source}), keeping the old version"),
+                    None => eprintln!("{e}, keeping the old version"),
+                },
+            }
+        }
+    }
+}
+
+//Measures the wall-clock time between frames
+struct FrameClock {
+    timer: LTimer,
+    last_ticks: Duration,
+}
+
+impl FrameClock {
+    fn new(timer: TimerSubsystem) -> Self {
+        let mut timer = LTimer::new(SdlClock::new(timer));
+        timer.start();
+        Self {
+            timer,
+            last_ticks: Duration::ZERO,
+        }
+    }
+
+    fn elapsed(&mut self) -> Duration {
+        let ticks = self.timer.get_ticks();
+        let elapsed = ticks - self.last_ticks;
+        self.last_ticks = ticks;
+        elapsed
+    }
+}
@@ -0,0 +1,385 @@
+//! Loads textures, fonts and sound chunks once and shares them through
+//! handles, so lesson types can hold on to assets without borrowing the
+//! renderer themselves. Files that change on disk can be reloaded in place
+//! while the program runs.
+
+use crate::audio::load_chunk;
+use crate::error::{Error, Result};
+use crate::font::load_font;
+use crate::texture::{LTexture, DEFAULT_COLOR_KEY};
+use sdl2::mixer::Chunk;
+use sdl2::pixels::Color;
+use sdl2::render::TextureCreator;
+use sdl2::ttf::{Font, Sdl2TtfContext};
+use sdl2::video::WindowContext;
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+//What an asset was loaded from; loading the same thing twice shares one copy
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Key {
+    Texture(PathBuf, Option<Color>),
+    Font(PathBuf, u16),
+    Chunk(PathBuf),
+}
+
+impl Key {
+    fn path(&self) -> &Path {
+        match self {
+            Self::Texture(path, _) | Self::Font(path, _) | Self::Chunk(path) => path,
+        }
+    }
+}
+
+enum Asset<'a> {
+    Texture(LTexture<'a>),
+    Font(Font<'a, 'static>),
+    Chunk(Chunk),
+}
+
+//Shared by every handle to one load of an asset; the cache keeps one more
+struct Slot {
+    key: Key,
+}
+
+//Enough of a file's metadata to notice that it was rewritten
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: SystemTime,
+    len: u64,
+}
+
+impl Stamp {
+    fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+}
+
+struct Entry<'a> {
+    asset: Asset<'a>,
+    bytes: usize,
+    slot: Rc<Slot>,
+    stamp: Option<Stamp>,
+    generation: u32,
+}
+
+/// Marks a [`Handle`] to a texture.
+pub enum TextureAsset {}
+/// Marks a [`Handle`] to a font.
+pub enum FontAsset {}
+/// Marks a [`Handle`] to a sound chunk.
+pub enum ChunkAsset {}
+
+/// A cheap, lifetime-free reference to something in an [`AssetCache`].
+/// The asset stays loaded while any handle to it exists, unless unloaded
+/// explicitly.
+pub struct Handle<K> {
+    slot: Rc<Slot>,
+    kind: PhantomData<K>,
+}
+
+pub type TextureHandle = Handle<TextureAsset>;
+pub type FontHandle = Handle<FontAsset>;
+pub type ChunkHandle = Handle<ChunkAsset>;
+
+impl<K> Handle<K> {
+    fn new(slot: Rc<Slot>) -> Self {
+        Self {
+            slot,
+            kind: PhantomData,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.slot.key.path()
+    }
+}
+
+impl<K> Clone for Handle<K> {
+    fn clone(&self) -> Self {
+        Self::new(Rc::clone(&self.slot))
+    }
+}
+
+impl<K> fmt::Debug for Handle<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Handle").field(&self.slot.key).finish()
+    }
+}
+
+/// What an [`AssetCache`] holds right now.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetStats {
+    pub textures: usize,
+    pub fonts: usize,
+    pub chunks: usize,
+    /// Estimated memory held: pixel data for textures, file size for fonts and chunks.
+    pub bytes: usize,
+}
+
+/// One asset [`AssetCache::reload_changed`] found changed on disk.
+#[derive(Debug)]
+pub struct Reload {
+    pub path: PathBuf,
+    /// Why the new file could not be used; the old asset stays loaded if so.
+    pub result: Result<()>,
+}
+
+/// Assets keyed by path, loaded on first request and shared after that.
+/// Clones are cheap and share the same assets, so a lesson can keep one.
+///
+/// Lookups borrow the cache, so don't hold a returned `Ref` across a load.
+///
+/// The cache itself still borrows the texture creator and TTF context for
+/// `'a`, because SDL's textures and fonts can't outlive them. Handles don't,
+/// so a type that holds only handles needs no lifetime, but one that keeps
+/// the cache (as most lessons do, to look its handles up) still carries `'a`.
+#[derive(Clone)]
+pub struct AssetCache<'a> {
+    texture_creator: &'a TextureCreator<WindowContext>,
+    ttf_context: &'a Sdl2TtfContext,
+    entries: Rc<RefCell<HashMap<Key, Entry<'a>>>>,
+}
+
+impl<'a> AssetCache<'a> {
+    pub fn new(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        ttf_context: &'a Sdl2TtfContext,
+    ) -> Self {
+        Self {
+            texture_creator,
+            ttf_context,
+            entries: Rc::default(),
+        }
+    }
+
+    /// Loads an image color keyed like [`LTexture::load_from_file`].
+    pub fn load_texture(&self, path: impl AsRef<Path>) -> Result<TextureHandle> {
+        self.load_texture_with_color_key(path, Some(DEFAULT_COLOR_KEY))
+    }
+
+    pub fn load_texture_with_color_key(
+        &self,
+        path: impl AsRef<Path>,
+        color_key: Option<Color>,
+    ) -> Result<TextureHandle> {
+        self.load(Key::Texture(normalize(path.as_ref()), color_key))
+    }
+
+    pub fn load_font(&self, path: impl AsRef<Path>, point_size: u16) -> Result<FontHandle> {
+        self.load(Key::Font(normalize(path.as_ref()), point_size))
+    }
+
+    pub fn load_chunk(&self, path: impl AsRef<Path>) -> Result<ChunkHandle> {
+        self.load(Key::Chunk(normalize(path.as_ref())))
+    }
+
+    pub fn texture(&self, handle: &TextureHandle) -> Result<Ref<'_, LTexture<'a>>> {
+        self.get(handle, |asset| match asset {
+            Asset::Texture(texture) => Some(texture),
+            _ => None,
+        })
+    }
+
+    /// For changing color, alpha or blend mode, which every holder of the
+    /// texture will see.
+    pub fn texture_mut(&self, handle: &TextureHandle) -> Result<RefMut<'_, LTexture<'a>>> {
+        let entries = self.entries.borrow_mut();
+        let slot = &handle.slot;
+        RefMut::filter_map(entries, |entries| match entries.get_mut(&slot.key) {
+            Some(Entry {
+                asset: Asset::Texture(texture),
+                slot: current,
+                ..
+            }) if Rc::ptr_eq(current, slot) => Some(texture),
+            _ => None,
+        })
+        .map_err(|_| unloaded(handle))
+    }
+
+    pub fn font(&self, handle: &FontHandle) -> Result<Ref<'_, Font<'a, 'static>>> {
+        self.get(handle, |asset| match asset {
+            Asset::Font(font) => Some(font),
+            _ => None,
+        })
+    }
+
+    pub fn chunk(&self, handle: &ChunkHandle) -> Result<Ref<'_, Chunk>> {
+        self.get(handle, |asset| match asset {
+            Asset::Chunk(chunk) => Some(chunk),
+            _ => None,
+        })
+    }
+
+    /// Drops the asset now, even if handles to it remain; using them is an
+    /// error until the asset is loaded again.
+    pub fn unload<K>(&self, handle: &Handle<K>) {
+        let mut entries = self.entries.borrow_mut();
+        if entries
+            .get(&handle.slot.key)
+            .is_some_and(|entry| Rc::ptr_eq(&entry.slot, &handle.slot))
+        {
+            entries.remove(&handle.slot.key);
+        }
+    }
+
+    /// Drops every asset nothing holds a handle to and returns how many went.
+    pub fn unload_unused(&self) -> usize {
+        let mut entries = self.entries.borrow_mut();
+        let before = entries.len();
+        entries.retain(|_, entry| Rc::strong_count(&entry.slot) > 1);
+        before - entries.len()
+    }
+
+    /// How many handles to the asset exist, or 0 if it was unloaded.
+    pub fn handle_count<K>(&self, handle: &Handle<K>) -> usize {
+        match self.entries.borrow().get(&handle.slot.key) {
+            Some(entry) if Rc::ptr_eq(&entry.slot, &handle.slot) => {
+                Rc::strong_count(&entry.slot) - 1
+            }
+            _ => 0,
+        }
+    }
+
+    /// How many times the asset has been reloaded, for things built from it
+    /// (like text rendered with a font) to notice they need rebuilding.
+    pub fn generation<K>(&self, handle: &Handle<K>) -> u32 {
+        match self.entries.borrow().get(&handle.slot.key) {
+            Some(entry) if Rc::ptr_eq(&entry.slot, &handle.slot) => entry.generation,
+            _ => 0,
+        }
+    }
+
+    /// Reloads every asset whose file changed since it was loaded, in place,
+    /// so existing handles see the new version. An asset whose new file fails
+    /// to load keeps its old version until the file changes again.
+    ///
+    /// Call it between frames: it panics if any asset is borrowed.
+    pub fn reload_changed(&self) -> Vec<Reload> {
+        let mut entries = self.entries.borrow_mut();
+        let mut reloads = Vec::new();
+        for (key, entry) in entries.iter_mut() {
+            //A missing file is usually one being saved; wait for it to come back
+            let stamp = Stamp::of(key.path());
+            if stamp.is_none() || stamp == entry.stamp {
+                continue;
+            }
+            entry.stamp = stamp;
+
+            let result = self.read(key).map(|(mut asset, bytes)| {
+                if let (Asset::Texture(new), Asset::Texture(old)) = (&mut asset, &entry.asset) {
+                    new.copy_modulation(old);
+                }
+                entry.asset = asset;
+                entry.bytes = bytes;
+                entry.generation += 1;
+            });
+            reloads.push(Reload {
+                path: key.path().to_path_buf(),
+                result,
+            });
+        }
+        reloads.sort_by(|a, b| a.path.cmp(&b.path));
+        reloads
+    }
+
+    pub fn stats(&self) -> AssetStats {
+        let entries = self.entries.borrow();
+        let mut stats = AssetStats::default();
+        for entry in entries.values() {
+            match entry.asset {
+                Asset::Texture(_) => stats.textures += 1,
+                Asset::Font(_) => stats.fonts += 1,
+                Asset::Chunk(_) => stats.chunks += 1,
+            }
+            stats.bytes += entry.bytes;
+        }
+        stats
+    }
+
+    fn load<K>(&self, key: Key) -> Result<Handle<K>> {
+        if let Some(entry) = self.entries.borrow().get(&key) {
+            return Ok(Handle::new(Rc::clone(&entry.slot)));
+        }
+
+        //Stamped first, so a write that races the load still counts as a change
+        let stamp = Stamp::of(key.path());
+        let (asset, bytes) = self.read(&key)?;
+        let slot = Rc::new(Slot { key: key.clone() });
+        self.entries.borrow_mut().insert(
+            key,
+            Entry {
+                asset,
+                bytes,
+                slot: Rc::clone(&slot),
+                stamp,
+                generation: 0,
+            },
+        );
+        Ok(Handle::new(slot))
+    }
+
+    //Loads the asset from disk, along with its estimated size
+    fn read(&self, key: &Key) -> Result<(Asset<'a>, usize)> {
+        match key {
+            Key::Texture(path, color_key) => {
+                let texture = LTexture::load_from_file_with_color_key(
+                    self.texture_creator,
+                    path,
+                    *color_key,
+                )?;
+                let bytes = texture.width() as usize
+                    * texture.height() as usize
+                    * texture.format().byte_size_per_pixel();
+                Ok((Asset::Texture(texture), bytes))
+            }
+            Key::Font(path, point_size) => {
+                let font = load_font(self.ttf_context, path, *point_size)?;
+                Ok((Asset::Font(font), file_size(path)))
+            }
+            Key::Chunk(path) => Ok((Asset::Chunk(load_chunk(path)?), file_size(path))),
+        }
+    }
+
+    fn get<K, T>(
+        &self,
+        handle: &Handle<K>,
+        pick: for<'e> fn(&'e Asset<'a>) -> Option<&'e T>,
+    ) -> Result<Ref<'_, T>> {
+        let entries = self.entries.borrow();
+        let slot = &handle.slot;
+        Ref::filter_map(entries, |entries| match entries.get(&slot.key) {
+            Some(entry) if Rc::ptr_eq(&entry.slot, slot) => pick(&entry.asset),
+            _ => None,
+        })
+        .map_err(|_| unloaded(handle))
+    }
+}
+
+fn unloaded<K>(handle: &Handle<K>) -> Error {
+    Error::Unloaded {
+        path: handle.path().to_path_buf(),
+    }
+}
+
+//So "./a.png" and "a.png" share an entry
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+//Fonts and chunks don't expose their decoded size, so their files stand in for it
+fn file_size(path: &Path) -> usize {
+    fs::metadata(path).map_or(0, |metadata| metadata.len() as usize)
+}
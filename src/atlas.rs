@@ -0,0 +1,379 @@
+//! Packs many images into a few large textures at startup, so drawing sprites
+//! from different files doesn't switch textures between every copy.
+
+use crate::error::{check_asset, Error, Result};
+use crate::texture::{LTexture, DEFAULT_COLOR_KEY};
+use sdl2::image::LoadSurface;
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::rect::Rect;
+use sdl2::render::{BlendMode, TextureCreator, WindowCanvas};
+use sdl2::surface::Surface;
+use sdl2::video::WindowContext;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Largest page built unless set otherwise; every renderer supports at least this.
+pub const DEFAULT_PAGE_SIZE: u32 = 2048;
+
+/// Transparent pixels left around every image so neighbours don't bleed into
+/// each other when scaled.
+pub const DEFAULT_PADDING: u32 = 1;
+
+/// Where an image ended up: which page, and where on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub page: usize,
+    pub rect: Rect,
+}
+
+/// Where [`pack`] put each image, in the order they were given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packing {
+    pub regions: Vec<Region>,
+    /// Size of each page actually used, padding included.
+    pub page_sizes: Vec<(u32, u32)>,
+}
+
+//A row of images as tall as the first one placed in it
+struct Shelf {
+    y: u32,
+    height: u32,
+    x: u32,
+}
+
+#[derive(Default)]
+struct Page {
+    shelves: Vec<Shelf>,
+    width: u32,
+    height: u32,
+}
+
+impl Page {
+    //Finds room for a `width` x `height` image, opening a new shelf if needed
+    fn place(
+        &mut self,
+        width: u32,
+        height: u32,
+        page_size: (u32, u32),
+        padding: u32,
+    ) -> Option<Rect> {
+        let (max_width, max_height) = page_size;
+        let fits_across = |x: u32| x + width + padding <= max_width;
+
+        let shelf = match self
+            .shelves
+            .iter_mut()
+            .find(|shelf| height <= shelf.height && fits_across(shelf.x))
+        {
+            Some(shelf) => shelf,
+            None => {
+                let y = self
+                    .shelves
+                    .last()
+                    .map_or(padding, |s| s.y + s.height + padding);
+                if y + height + padding > max_height || !fits_across(padding) {
+                    return None;
+                }
+                self.shelves.push(Shelf {
+                    y,
+                    height,
+                    x: padding,
+                });
+                self.shelves.last_mut()?
+            }
+        };
+
+        let rect = Rect::new(shelf.x as i32, shelf.y as i32, width, height);
+        shelf.x += width + padding;
+        self.width = self.width.max(shelf.x);
+        self.height = self.height.max(shelf.y + shelf.height + padding);
+        Some(rect)
+    }
+}
+
+/// Shelf-packs images of the given sizes onto as few pages of at most `page_size`
+/// as it can, tallest first, with `padding` around each. Fails with the index of
+/// the first image too big for an empty page.
+pub fn pack(sizes: &[(u32, u32)], page_size: (u32, u32), padding: u32) -> Result<Packing, usize> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(sizes[i].1));
+
+    let mut pages: Vec<Page> = Vec::new();
+    let mut regions = vec![None; sizes.len()];
+    for i in order {
+        let (width, height) = sizes[i];
+        let placed = pages.iter_mut().enumerate().find_map(|(page, p)| {
+            p.place(width, height, page_size, padding)
+                .map(|rect| Region { page, rect })
+        });
+        let region = match placed {
+            Some(region) => region,
+            None => {
+                let mut page = Page::default();
+                let rect = page.place(width, height, page_size, padding).ok_or(i)?;
+                pages.push(page);
+                Region {
+                    page: pages.len() - 1,
+                    rect,
+                }
+            }
+        };
+        regions[i] = Some(region);
+    }
+
+    Ok(Packing {
+        regions: regions.into_iter().flatten().collect(),
+        page_sizes: pages.iter().map(|page| (page.width, page.height)).collect(),
+    })
+}
+
+struct Entry {
+    name: String,
+    path: PathBuf,
+    tiles: Option<Tiles>,
+}
+
+//How an image is cut up, relative to its top left corner
+enum Tiles {
+    Grid(u32, u32),
+    Clips(Vec<Rect>),
+}
+
+/// Collects images to pack into an [`Atlas`]. Nothing is loaded until
+/// [`build`](Self::build).
+pub struct AtlasBuilder {
+    entries: Vec<Entry>,
+    page_size: (u32, u32),
+    padding: u32,
+}
+
+impl AtlasBuilder {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            page_size: (DEFAULT_PAGE_SIZE, DEFAULT_PAGE_SIZE),
+            padding: DEFAULT_PADDING,
+        }
+    }
+
+    pub fn with_page_size(mut self, width: u32, height: u32) -> Self {
+        self.page_size = (width, height);
+        self
+    }
+
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Adds the image at `path` as the region `name`.
+    pub fn add(mut self, name: &str, path: impl AsRef<Path>) -> Self {
+        self.entries.push(Entry {
+            name: name.to_string(),
+            path: path.as_ref().to_path_buf(),
+            tiles: None,
+        });
+        self
+    }
+
+    /// Adds a sprite sheet as the region `name`, and each whole
+    /// `tile_width` x `tile_height` tile of it, left to right and top to
+    /// bottom, as [`Atlas::tiles`] of `name`.
+    pub fn add_sheet(
+        mut self,
+        name: &str,
+        path: impl AsRef<Path>,
+        tile_width: u32,
+        tile_height: u32,
+    ) -> Self {
+        self.entries.push(Entry {
+            name: name.to_string(),
+            path: path.as_ref().to_path_buf(),
+            tiles: Some(Tiles::Grid(tile_width, tile_height)),
+        });
+        self
+    }
+
+    /// Adds a sprite sheet as the region `name`, with `clips` of it, such as the
+    /// frames of a [`Sheet`](crate::sheet::Sheet), as [`Atlas::tiles`] of `name`.
+    pub fn add_clips(
+        mut self,
+        name: &str,
+        path: impl AsRef<Path>,
+        clips: impl IntoIterator<Item = Rect>,
+    ) -> Self {
+        self.entries.push(Entry {
+            name: name.to_string(),
+            path: path.as_ref().to_path_buf(),
+            tiles: Some(Tiles::Clips(clips.into_iter().collect())),
+        });
+        self
+    }
+
+    /// Loads every image, color keyed like [`LTexture::load_from_file`], and
+    /// packs them into textures.
+    pub fn build<'a>(
+        self,
+        texture_creator: &'a TextureCreator<WindowContext>,
+    ) -> Result<Atlas<'a>> {
+        let surfaces = self
+            .entries
+            .iter()
+            .map(|entry| load_surface(&entry.path))
+            .collect::<Result<Vec<_>>>()?;
+
+        let sizes: Vec<_> = surfaces.iter().map(|surface| surface.size()).collect();
+        //A clip hanging off its image would pick up whatever is packed next to it
+        for (entry, &(width, height)) in self.entries.iter().zip(&sizes) {
+            if let Some(Tiles::Clips(clips)) = &entry.tiles {
+                let bounds = Rect::new(0, 0, width, height);
+                if let Some(clip) = clips.iter().find(|clip| !bounds.contains_rect(**clip)) {
+                    return Err(Error::decode(
+                        &entry.path,
+                        format!("clip {clip:?} lies outside the {width}x{height} image"),
+                    ));
+                }
+            }
+        }
+        let packing = pack(&sizes, self.page_size, self.padding).map_err(|i| {
+            let (width, height) = sizes[i];
+            let (max_width, max_height) = self.page_size;
+            Error::decode(
+                &self.entries[i].path,
+                format!(
+                    "{width}x{height} image does not fit on a {max_width}x{max_height} atlas page"
+                ),
+            )
+        })?;
+
+        let mut pages = packing
+            .page_sizes
+            .iter()
+            .map(|&(width, height)| {
+                Surface::new(width, height, PixelFormatEnum::RGBA32).map_err(Error::render)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (surface, region) in surfaces.iter().zip(&packing.regions) {
+            surface
+                .blit(None, &mut pages[region.page], region.rect)
+                .map_err(Error::render)?;
+        }
+
+        let pages = pages
+            .iter()
+            .map(|page| {
+                let mut texture = texture_creator
+                    .create_texture_from_surface(page)
+                    .map_err(Error::render)?;
+                texture.set_blend_mode(BlendMode::Blend);
+                Ok(LTexture::new(texture))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let regions = self
+            .entries
+            .into_iter()
+            .zip(packing.regions)
+            .map(|(entry, region)| {
+                let tiles = entry
+                    .tiles
+                    .map_or_else(Vec::new, |tiles| place_tiles(region, tiles));
+                (entry.name, (region, tiles))
+            })
+            .collect();
+        Ok(Atlas { pages, regions })
+    }
+}
+
+impl Default for AtlasBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//Loads with the color key turned into transparency, copying alpha as-is when blitted
+fn load_surface(path: &Path) -> Result<Surface<'static>> {
+    check_asset(path)?;
+    let mut surface = Surface::from_file(path).map_err(|e| Error::decode(path, e))?;
+    surface
+        .set_color_key(true, DEFAULT_COLOR_KEY)
+        .map_err(|e| Error::decode(path, e))?;
+    surface
+        .set_blend_mode(BlendMode::None)
+        .map_err(|e| Error::decode(path, e))?;
+    Ok(surface)
+}
+
+//Moves tiles from image coordinates to where the image was packed
+fn place_tiles(sheet: Region, tiles: Tiles) -> Vec<Region> {
+    let clips = match tiles {
+        Tiles::Clips(clips) => clips,
+        Tiles::Grid(0, _) | Tiles::Grid(_, 0) => Vec::new(),
+        Tiles::Grid(tile_width, tile_height) => {
+            let columns = sheet.rect.width() / tile_width;
+            let rows = sheet.rect.height() / tile_height;
+            (0..rows)
+                .flat_map(|row| (0..columns).map(move |column| (column, row)))
+                .map(|(column, row)| {
+                    Rect::new(
+                        (column * tile_width) as i32,
+                        (row * tile_height) as i32,
+                        tile_width,
+                        tile_height,
+                    )
+                })
+                .collect()
+        }
+    };
+    clips
+        .into_iter()
+        .map(|clip| Region {
+            page: sheet.page,
+            rect: Rect::new(
+                sheet.rect.x() + clip.x(),
+                sheet.rect.y() + clip.y(),
+                clip.width(),
+                clip.height(),
+            ),
+        })
+        .collect()
+}
+
+/// Images packed into shared textures, looked up by name.
+pub struct Atlas<'a> {
+    pages: Vec<LTexture<'a>>,
+    regions: HashMap<String, (Region, Vec<Region>)>,
+}
+
+impl<'a> Atlas<'a> {
+    pub fn pages(&self) -> &[LTexture<'a>] {
+        &self.pages
+    }
+
+    pub fn region(&self, name: &str) -> Result<Region> {
+        self.regions
+            .get(name)
+            .map(|(region, _)| *region)
+            .ok_or_else(|| missing(name))
+    }
+
+    /// Tiles of a sheet added with [`AtlasBuilder::add_sheet`] or
+    /// [`AtlasBuilder::add_clips`]; empty for plain images.
+    pub fn tiles(&self, name: &str) -> Result<&[Region]> {
+        self.regions
+            .get(name)
+            .map(|(_, tiles)| tiles.as_slice())
+            .ok_or_else(|| missing(name))
+    }
+
+    /// Draws `region` with its top left corner at (`x`, `y`).
+    pub fn render(&self, canvas: &mut WindowCanvas, region: Region, x: i32, y: i32) -> Result<()> {
+        self.pages[region.page].render(canvas, x, y, Some(region.rect))
+    }
+}
+
+fn missing(name: &str) -> Error {
+    Error::MissingRegion {
+        name: name.to_string(),
+    }
+}
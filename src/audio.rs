@@ -0,0 +1,25 @@
+use crate::error::{check_asset, Error, Result};
+use sdl2::mixer::{Chunk, Music, DEFAULT_FORMAT};
+use std::path::Path;
+
+pub fn open_audio() -> Result<()> {
+    sdl2::mixer::open_audio(44100, DEFAULT_FORMAT, 2, 2048).map_err(|e| Error::init("audio", e))
+}
+
+pub fn load_chunk(path: impl AsRef<Path>) -> Result<Chunk> {
+    let path = path.as_ref();
+    check_asset(path)?;
+    Chunk::from_file(path).map_err(|e| Error::Mixer {
+        path: Some(path.to_path_buf()),
+        source: e.into(),
+    })
+}
+
+pub fn load_music(path: impl AsRef<Path>) -> Result<Music<'static>> {
+    let path = path.as_ref();
+    check_asset(path)?;
+    Music::from_file(path).map_err(|e| Error::Mixer {
+        path: Some(path.to_path_buf()),
+        source: e.into(),
+    })
+}
@@ -0,0 +1,225 @@
+//! Text drawn from a sheet of glyph images rather than a TrueType font, for
+//! pixel-art games that ship their own lettering.
+
+use crate::error::{check_asset, Error, Result};
+use crate::pixels::{Pixels, TRANSPARENT};
+use crate::render_queue::GlyphSource;
+use crate::texture::LTexture;
+use sdl2::image::LoadSurface;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::surface::Surface;
+use sdl2::video::WindowContext;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Glyph sheets hold the first 256 character codes in a square grid this many cells wide.
+pub const GRID_SIZE: u32 = 16;
+
+/// Where each glyph is on a sheet and how to lay out text with them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    rects: Vec<Option<Rect>>,
+    line_height: u32,
+    space_width: u32,
+    spacing: i32,
+    line_spacing: i32,
+    kerning: HashMap<(char, char), i32>,
+}
+
+impl Glyphs {
+    /// Finds the glyphs in a [`GRID_SIZE`] by [`GRID_SIZE`] grid of cells,
+    /// taking the top left pixel as the background color. Each glyph is
+    /// cropped to its own width; all share one height so they line up on a
+    /// baseline. Returns `None` if the grid holds no glyphs at all.
+    pub fn detect(pixels: &Pixels) -> Option<Self> {
+        let background = pixels.get(0, 0)?;
+        let cell_width = pixels.width() / GRID_SIZE;
+        let cell_height = pixels.height() / GRID_SIZE;
+
+        //Each glyph's columns, and the rows every glyph together spans
+        let mut columns = Vec::with_capacity((GRID_SIZE * GRID_SIZE) as usize);
+        let (mut top, mut bottom) = (cell_height, 0);
+        for cell in 0..GRID_SIZE * GRID_SIZE {
+            let cell_x = (cell % GRID_SIZE * cell_width) as i32;
+            let cell_y = (cell / GRID_SIZE * cell_height) as i32;
+            let mut found: Option<(u32, u32)> = None;
+            for y in 0..cell_height {
+                for x in 0..cell_width {
+                    if pixels.get(cell_x + x as i32, cell_y + y as i32) != Some(background) {
+                        found = Some(found.map_or((x, x), |(l, r)| (l.min(x), r.max(x))));
+                        top = top.min(y);
+                        bottom = bottom.max(y);
+                    }
+                }
+            }
+            columns.push(found);
+        }
+        if top > bottom {
+            return None;
+        }
+
+        let line_height = bottom - top + 1;
+        let rects = columns
+            .iter()
+            .enumerate()
+            .map(|(cell, found)| {
+                let (left, right) = (*found)?;
+                let cell = cell as u32;
+                Some(Rect::new(
+                    (cell % GRID_SIZE * cell_width + left) as i32,
+                    (cell / GRID_SIZE * cell_height + top) as i32,
+                    right - left + 1,
+                    line_height,
+                ))
+            })
+            .collect();
+        Some(Self {
+            rects,
+            line_height,
+            space_width: cell_width / 2,
+            spacing: 0,
+            line_spacing: 0,
+            kerning: HashMap::new(),
+        })
+    }
+
+    /// Extra pixels between letters; negative values pull them together.
+    pub fn with_spacing(mut self, spacing: i32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Extra pixels between lines.
+    pub fn with_line_spacing(mut self, line_spacing: i32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    /// Moves the second letter of each pair by the given pixels when it
+    /// follows the first, on top of the usual spacing.
+    pub fn with_kerning(mut self, pairs: impl IntoIterator<Item = ((char, char), i32)>) -> Self {
+        self.kerning.extend(pairs);
+        self
+    }
+
+    /// Where `c` is on the sheet, if the sheet has it.
+    pub fn glyph(&self, c: char) -> Option<Rect> {
+        self.rects.get(c as usize).copied().flatten()
+    }
+
+    pub fn line_height(&self) -> u32 {
+        self.line_height
+    }
+
+    /// Where each glyph of `text` goes relative to where the text starts,
+    /// with the clip to draw there. Spaces and characters the sheet lacks
+    /// leave a gap half a cell wide.
+    pub fn layout(&self, text: &str) -> Vec<(Rect, Point)> {
+        let mut placed = Vec::new();
+        self.walk(text, |clip, at| placed.push((clip, at)));
+        placed
+    }
+
+    /// The width of the widest line of `text` and the height of all of them.
+    pub fn measure(&self, text: &str) -> (u32, u32) {
+        self.walk(text, |_, _| {})
+    }
+
+    fn walk(&self, text: &str, mut place: impl FnMut(Rect, Point)) -> (u32, u32) {
+        let line_advance = self.line_height as i32 + self.line_spacing;
+        let (mut x, mut y, mut width) = (0, 0, 0);
+        let mut previous = None;
+        for c in text.chars() {
+            if c == '\n' {
+                x = 0;
+                y += line_advance;
+                previous = None;
+                continue;
+            }
+
+            if let Some(kerning) = previous.and_then(|p| self.kerning.get(&(p, c))) {
+                x += kerning;
+            }
+            let advance = match self.glyph(c) {
+                Some(clip) => {
+                    place(clip, Point::new(x, y));
+                    clip.width()
+                }
+                None => self.space_width,
+            } as i32;
+            width = width.max(x + advance);
+            x += advance + self.spacing;
+            previous = Some(c);
+        }
+        (width.max(0) as u32, (y + self.line_height as i32) as u32)
+    }
+}
+
+/// A glyph sheet loaded into a texture, ready to draw text with.
+pub struct BitmapFont<'a> {
+    texture: LTexture<'a>,
+    glyphs: Glyphs,
+}
+
+impl<'a> BitmapFont<'a> {
+    /// Loads a glyph sheet laid out as described in [`Glyphs::detect`]. The
+    /// background color becomes transparent.
+    pub fn load(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let surface = Surface::from_file(path).map_err(|e| Error::decode(path, e))?;
+        let mut pixels = Pixels::from_surface(&surface)?;
+        let glyphs = Glyphs::detect(&pixels)
+            .ok_or_else(|| Error::decode(path, "the glyph sheet is empty"))?;
+
+        if let Some(background) = pixels.get(0, 0) {
+            pixels.replace_color(background, TRANSPARENT);
+        }
+        Ok(Self {
+            texture: LTexture::from_pixels(texture_creator, pixels)?,
+            glyphs,
+        })
+    }
+
+    pub fn glyphs(&self) -> &Glyphs {
+        &self.glyphs
+    }
+
+    /// Changes the spacing and kerning with the builder methods of [`Glyphs`].
+    pub fn with_glyphs(mut self, change: impl FnOnce(Glyphs) -> Glyphs) -> Self {
+        self.glyphs = change(self.glyphs);
+        self
+    }
+
+    /// Tints the glyphs, which works best on sheets drawn in white.
+    pub fn set_color(&mut self, r: u8, g: u8, b: u8) {
+        self.texture.set_color(r, g, b);
+    }
+
+    /// Draws `text` with its top left corner at (`x`, `y`).
+    pub fn render(&self, canvas: &mut WindowCanvas, x: i32, y: i32, text: &str) -> Result<()> {
+        let mut result = Ok(());
+        self.glyphs.walk(text, |clip, at| {
+            if result.is_ok() {
+                result = self
+                    .texture
+                    .render(canvas, x + at.x(), y + at.y(), Some(clip));
+            }
+        });
+        result
+    }
+}
+
+impl GlyphSource for BitmapFont<'_> {
+    fn glyph_texture(&self) -> &LTexture<'_> {
+        &self.texture
+    }
+
+    fn place_glyphs(&self, text: &str, place: &mut dyn FnMut(Rect, Point)) {
+        self.glyphs.walk(text, place);
+    }
+}
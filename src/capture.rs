@@ -0,0 +1,269 @@
+//! Saves what the canvas shows: single screenshots, or a run of frames as an
+//! animated GIF or numbered PNGs. Works on the dummy video driver too, so
+//! lessons can be captured without a display.
+
+use crate::error::{check_asset, Error, Result, Source};
+use crate::timestep::TICKS_PER_SECOND;
+use sdl2::image::{LoadSurface, SaveSurface};
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::render::WindowCanvas;
+use sdl2::surface::Surface;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Saves a screenshot of the running lesson.
+pub const SCREENSHOT_KEY: Keycode = Keycode::F12;
+
+/// Where screenshots are saved, relative to the working directory.
+pub const SCREENSHOT_DIR: &str = "screenshots";
+
+//Trades GIF encoding speed for color quality, from 1 (best) to 30
+const GIF_QUANTIZER_SPEED: i32 = 10;
+
+/// RGBA pixels read back from a canvas or an image file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl Snapshot {
+    /// What `canvas` currently shows.
+    pub fn read(canvas: &WindowCanvas) -> Result<Self> {
+        let (width, height) = canvas.output_size().map_err(Error::render)?;
+        let pixels = canvas
+            .read_pixels(None, PixelFormatEnum::RGBA32)
+            .map_err(Error::render)?;
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn load_png(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let surface = Surface::from_file(path)
+            .and_then(|surface| surface.convert_format(PixelFormatEnum::RGBA32))
+            .map_err(|e| Error::decode(path, e))?;
+
+        let (width, height) = surface.size();
+        let pitch = surface.pitch() as usize;
+        let row = width as usize * 4;
+        let pixels = surface.with_lock(|data| {
+            data.chunks(pitch)
+                .take(height as usize)
+                .flat_map(|line| &line[..row])
+                .copied()
+                .collect()
+        });
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| Error::encode(path, e))?;
+        }
+
+        let mut pixels = self.pixels.clone();
+        let surface = Surface::from_data(
+            &mut pixels,
+            self.width,
+            self.height,
+            self.width * 4,
+            PixelFormatEnum::RGBA32,
+        )
+        .map_err(|e| Error::encode(path, e))?;
+        surface.save(path).map_err(|e| Error::encode(path, e))
+    }
+
+    /// Counts the pixels where any channel differs from `other` by more than `tolerance`.
+    /// Snapshots of different sizes differ everywhere.
+    pub fn count_mismatches(&self, other: &Snapshot, tolerance: u8) -> usize {
+        if (self.width, self.height) != (other.width, other.height) {
+            return (self.width * self.height).max(other.width * other.height) as usize;
+        }
+        self.pixels
+            .chunks(4)
+            .zip(other.pixels.chunks(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > tolerance)
+            })
+            .count()
+    }
+
+    /// Marks mismatched pixels red over a faded copy of `self`.
+    pub fn diff(&self, other: &Snapshot, tolerance: u8) -> Snapshot {
+        let mut pixels = Vec::with_capacity(self.pixels.len());
+        for (i, a) in self.pixels.chunks(4).enumerate() {
+            let b = other.pixels.get(i * 4..i * 4 + 4).unwrap_or(&[0; 4]);
+            if a.iter()
+                .zip(b.iter())
+                .any(|(a, b)| a.abs_diff(*b) > tolerance)
+            {
+                pixels.extend_from_slice(&[0xff, 0, 0, 0xff]);
+            } else {
+                let gray = ((a[0] as u32 + a[1] as u32 + a[2] as u32) / 3 / 4 + 0xbf) as u8;
+                pixels.extend_from_slice(&[gray, gray, gray, 0xff]);
+            }
+        }
+        Snapshot {
+            width: self.width,
+            height: self.height,
+            pixels,
+        }
+    }
+}
+
+/// Saves what `canvas` shows to `<dir>/<name>-NNN.png`, numbered after the
+/// screenshots already there, and returns the path.
+pub fn screenshot(canvas: &WindowCanvas, dir: impl AsRef<Path>, name: &str) -> Result<PathBuf> {
+    let dir = dir.as_ref();
+    let path = (1..)
+        .map(|n| dir.join(format!("{name}-{n:03}.png")))
+        .find(|path| !path.exists())
+        .expect("screenshot numbers run out");
+    Snapshot::read(canvas)?.save_png(&path)?;
+    Ok(path)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaptureFormat {
+    /// One animated GIF, looping forever.
+    Gif,
+    /// A directory of `frame-NNNN.png` files, numbered by frame.
+    PngSequence,
+}
+
+/// Records a range of frames, counted from the first one passed to
+/// [`FrameCapture::frame`].
+pub struct FrameCapture {
+    path: PathBuf,
+    format: CaptureFormat,
+    frames: Range<u32>,
+    frame_delay: Duration,
+    next_frame: u32,
+    captured: u32,
+    //Opened on the first frame, once its size is known
+    gif: Option<gif::Encoder<BufWriter<File>>>,
+}
+
+impl FrameCapture {
+    /// Captures `frames` to `path`: a GIF if it ends in `.gif`, otherwise a
+    /// directory of PNGs.
+    pub fn new(path: impl Into<PathBuf>, frames: Range<u32>) -> Self {
+        let path = path.into();
+        let format = match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("gif") => CaptureFormat::Gif,
+            _ => CaptureFormat::PngSequence,
+        };
+        Self {
+            path,
+            format,
+            frames,
+            frame_delay: Duration::from_secs(1) / TICKS_PER_SECOND,
+            next_frame: 0,
+            captured: 0,
+            gif: None,
+        }
+    }
+
+    /// How long each GIF frame is shown, one tick by default. GIFs count in
+    /// hundredths of a second and most viewers slow anything under two of
+    /// them down, so shorter delays play at 50 frames a second.
+    pub fn with_frame_delay(mut self, frame_delay: Duration) -> Self {
+        self.frame_delay = frame_delay;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn frames(&self) -> Range<u32> {
+        self.frames.clone()
+    }
+
+    pub fn format(&self) -> CaptureFormat {
+        self.format
+    }
+
+    /// Whether every frame in the range has gone by.
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.frames.end
+    }
+
+    /// Counts a frame, saving what `canvas` shows if the frame is in range.
+    pub fn frame(&mut self, canvas: &WindowCanvas) -> Result<()> {
+        let frame = self.next_frame;
+        self.next_frame += 1;
+        if !self.frames.contains(&frame) {
+            return Ok(());
+        }
+
+        let snapshot = Snapshot::read(canvas)?;
+        match self.format {
+            CaptureFormat::Gif => self
+                .write_gif_frame(snapshot)
+                .map_err(|e| Error::encode(&self.path, e))?,
+            CaptureFormat::PngSequence => {
+                snapshot.save_png(self.path.join(format!("frame-{frame:04}.png")))?
+            }
+        }
+        self.captured += 1;
+        Ok(())
+    }
+
+    /// Finishes writing and returns how many frames were captured.
+    pub fn finish(self) -> Result<u32> {
+        if let Some(encoder) = self.gif {
+            encoder
+                .into_inner()
+                .and_then(|mut file| file.flush().map_err(Into::into))
+                .map_err(|e| Error::encode(&self.path, e))?;
+        }
+        Ok(self.captured)
+    }
+
+    fn write_gif_frame(&mut self, mut snapshot: Snapshot) -> std::result::Result<(), Source> {
+        let width = u16::try_from(snapshot.width)?;
+        let height = u16::try_from(snapshot.height)?;
+        let delay = self.gif_delay();
+        let encoder = match &mut self.gif {
+            Some(encoder) => encoder,
+            None => {
+                if let Some(dir) = self.path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                let file = BufWriter::new(File::create(&self.path)?);
+                let mut encoder = gif::Encoder::new(file, width, height, &[])?;
+                encoder.set_repeat(gif::Repeat::Infinite)?;
+                self.gif.insert(encoder)
+            }
+        };
+
+        let mut frame =
+            gif::Frame::from_rgba_speed(width, height, &mut snapshot.pixels, GIF_QUANTIZER_SPEED);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+        Ok(())
+    }
+
+    fn gif_delay(&self) -> u16 {
+        let hundredths = (self.frame_delay.as_secs_f64() * 100.0).round();
+        hundredths.clamp(2.0, u16::MAX as f64) as u16
+    }
+}
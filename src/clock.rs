@@ -0,0 +1,66 @@
+use sdl2::TimerSubsystem;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Monotonic time source for [`LTimer`](crate::LTimer) and friends.
+pub trait Clock {
+    /// Time since some fixed, clock-specific origin.
+    fn now(&self) -> Duration;
+}
+
+/// Wall-clock time from SDL's high-resolution performance counter.
+pub struct SdlClock {
+    timer: TimerSubsystem,
+}
+
+impl SdlClock {
+    pub fn new(timer: TimerSubsystem) -> Self {
+        Self { timer }
+    }
+}
+
+impl Clock for SdlClock {
+    fn now(&self) -> Duration {
+        let counter = self.timer.performance_counter();
+        let frequency = self.timer.performance_frequency();
+        let nanos = (counter % frequency) as u128 * 1_000_000_000 / frequency as u128;
+        Duration::new(counter / frequency, nanos as u32)
+    }
+}
+
+/// Clock that only moves when told to, for tests and deterministic replays.
+///
+/// Clones share the same time, so a test can keep one and hand another to the
+/// code under test.
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A clock reading `now` until advanced.
+    pub fn starting_at(now: Duration) -> Self {
+        Self {
+            now: Rc::new(Cell::new(now)),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
@@ -0,0 +1,592 @@
+//! Shapes that things collide as, one test for whether any two overlap, how
+//! to push them apart when they do, and when a moving shape first hits
+//! another.
+//!
+//! Every shape is its inside only, so shapes that merely touch along an edge
+//! or at a point don't intersect. Whether shapes intersect is exact on whole
+//! pixels; ways out and times of impact are measured in floats.
+
+use sdl2::rect::{FPoint, Point, Rect};
+
+/// A disc around (`x`, `y`). A radius of 0 or less covers nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Circle {
+    pub x: i32,
+    pub y: i32,
+    pub r: i32,
+}
+
+impl Circle {
+    pub fn new(x: i32, y: i32, r: i32) -> Self {
+        Self { x, y, r }
+    }
+}
+
+/// A convex polygon with some area, its corners in either winding order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    points: Vec<Point>,
+    //Whether the corners go counter-clockwise with y pointing up, i.e.
+    //clockwise on screen
+    counter_clockwise: bool,
+}
+
+impl Polygon {
+    /// Returns `None` unless `points` make a convex polygon with some area.
+    /// Corners in a straight line with their neighbours are allowed.
+    pub fn new(points: Vec<Point>) -> Option<Self> {
+        let area = twice_area(&points);
+        if area == 0 {
+            return None;
+        }
+        let counter_clockwise = area > 0;
+        let next = edges(&points).skip(1).chain(edges(&points).take(1));
+        for ((a, b), (_, c)) in edges(&points).zip(next) {
+            let turn = cross(b - a, c - b);
+            if turn != 0 && (turn > 0) != counter_clockwise {
+                return None;
+            }
+        }
+        //Turning one way isn't enough: a star turns one way too, but goes
+        //left and right more than once on its way around
+        let across: Vec<_> = edges(&points)
+            .map(|(a, b)| (b - a).x().signum())
+            .filter(|&direction| direction != 0)
+            .collect();
+        let changes = across
+            .iter()
+            .zip(across.iter().cycle().skip(1))
+            .filter(|(a, b)| a != b)
+            .count();
+        if changes > 2 {
+            return None;
+        }
+        Some(Self {
+            points,
+            counter_clockwise,
+        })
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// The polygon moved by (`dx`, `dy`).
+    pub fn offset(&self, dx: i32, dy: i32) -> Self {
+        Self {
+            points: self.points.iter().map(|p| p.offset(dx, dy)).collect(),
+            counter_clockwise: self.counter_clockwise,
+        }
+    }
+
+    //Whether `p` is inside or on the edge
+    fn covers(&self, p: Point) -> bool {
+        edges(&self.points).all(|(a, b)| {
+            let side = cross(b - a, p - a);
+            side == 0 || (side > 0) == self.counter_clockwise
+        })
+    }
+}
+
+/// What something collides as.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// An axis-aligned box.
+    Aabb(Rect),
+    Circle(Circle),
+    /// Several boxes together, e.g. one per row of a sprite's pixels.
+    Boxes(Vec<Rect>),
+    Polygon(Polygon),
+}
+
+impl Shape {
+    pub fn intersects(&self, other: &Shape) -> bool {
+        intersects(self, other)
+    }
+
+    pub fn contact(&self, other: &Shape) -> Option<Manifold> {
+        contact(self, other)
+    }
+
+    pub fn sweep(&self, motion: FPoint, other: &Shape) -> Option<Impact> {
+        sweep(self, motion, other)
+    }
+
+    /// The shape moved by (`dx`, `dy`).
+    pub fn offset(&self, dx: i32, dy: i32) -> Self {
+        match self {
+            Shape::Aabb(rect) => Shape::Aabb(offset_rect(*rect, dx, dy)),
+            Shape::Circle(circle) => {
+                Shape::Circle(Circle::new(circle.x + dx, circle.y + dy, circle.r))
+            }
+            Shape::Boxes(boxes) => Shape::Boxes(
+                boxes
+                    .iter()
+                    .map(|rect| offset_rect(*rect, dx, dy))
+                    .collect(),
+            ),
+            Shape::Polygon(polygon) => Shape::Polygon(polygon.offset(dx, dy)),
+        }
+    }
+
+    /// The smallest box around the shape, or `None` if it covers nothing.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.parts()
+            .filter_map(|part| match part {
+                Part::Rect(rect) => Some(rect),
+                Part::Circle(c) if c.r > 0 => Some(Rect::new(
+                    c.x - c.r,
+                    c.y - c.r,
+                    c.r as u32 * 2,
+                    c.r as u32 * 2,
+                )),
+                Part::Circle(_) => None,
+                Part::Polygon(polygon) => {
+                    let points = polygon.points();
+                    let (xs, ys) = (points.iter().map(|p| p.x()), points.iter().map(|p| p.y()));
+                    let (left, top) = (xs.clone().min()?, ys.clone().min()?);
+                    let (right, bottom) = (xs.max()?, ys.max()?);
+                    Some(Rect::new(
+                        left,
+                        top,
+                        (right - left) as u32,
+                        (bottom - top) as u32,
+                    ))
+                }
+            })
+            .reduce(|a, b| a.union(b))
+    }
+
+    fn parts(&self) -> impl Iterator<Item = Part<'_>> {
+        let (single, boxes) = match self {
+            Shape::Aabb(rect) => (Some(Part::Rect(*rect)), &[][..]),
+            Shape::Circle(circle) => (Some(Part::Circle(*circle)), &[][..]),
+            Shape::Polygon(polygon) => (Some(Part::Polygon(polygon)), &[][..]),
+            Shape::Boxes(boxes) => (None, &boxes[..]),
+        };
+        single
+            .into_iter()
+            .chain(boxes.iter().map(|rect| Part::Rect(*rect)))
+    }
+}
+
+/// Whether the insides of `a` and `b` overlap.
+pub fn intersects(a: &Shape, b: &Shape) -> bool {
+    a.parts().any(|a| b.parts().any(|b| parts_intersect(a, b)))
+}
+
+/// How far one shape has gone into another, and which way is out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Manifold {
+    /// Unit vector pointing out of the other shape.
+    pub normal: FPoint,
+    /// How far to move along `normal` until the shapes only touch.
+    pub depth: f32,
+}
+
+impl Manifold {
+    /// The minimum translation vector: the shortest move that separates the
+    /// shapes.
+    pub fn translation(&self) -> FPoint {
+        FPoint::new(self.normal.x * self.depth, self.normal.y * self.depth)
+    }
+}
+
+/// How to push `a` out of `b`, or `None` if they don't intersect. Shapes made
+/// of several boxes report their deepest pair of boxes, so moving out of one
+/// contact may leave another to resolve.
+pub fn contact(a: &Shape, b: &Shape) -> Option<Manifold> {
+    a.parts()
+        .flat_map(|a| b.parts().filter_map(move |b| parts_contact(a, b)))
+        .max_by(|x, y| x.depth.total_cmp(&y.depth))
+}
+
+/// When and where a moving shape first runs into another.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Impact {
+    /// How far through the move the shapes first touch, from 0 up to 1.
+    pub time: f32,
+    /// Unit vector pointing out of the shape that was hit.
+    pub normal: FPoint,
+}
+
+/// When `a`, moved by `motion`, first runs into `b`, or `None` if it gets all
+/// the way without going inside. Unlike checking where `a` ends up, this
+/// catches fast shapes passing through thin ones. Pieces that already
+/// intersect are left to [`contact`].
+pub fn sweep(a: &Shape, motion: FPoint, b: &Shape) -> Option<Impact> {
+    let motion = (motion.x as f64, motion.y as f64);
+    a.parts()
+        .flat_map(|a| b.parts().filter_map(move |b| parts_sweep(a, motion, b)))
+        .min_by(|x, y| x.0.total_cmp(&y.0))
+        .map(|(time, (x, y))| Impact {
+            time: time as f32,
+            normal: FPoint::new(x as f32, y as f32),
+        })
+}
+
+//A single convex piece of a shape
+#[derive(Clone, Copy)]
+enum Part<'s> {
+    Rect(Rect),
+    Circle(Circle),
+    Polygon(&'s Polygon),
+}
+
+fn parts_intersect(a: Part, b: Part) -> bool {
+    match (a, b) {
+        (Part::Rect(a), Part::Rect(b)) => rects_intersect(a, b),
+        (Part::Circle(a), Part::Circle(b)) => circles_intersect(a, b),
+        (Part::Circle(c), Part::Rect(r)) | (Part::Rect(r), Part::Circle(c)) => {
+            circle_intersects_rect(c, r)
+        }
+        (Part::Polygon(a), Part::Polygon(b)) => polygons_intersect(&a.points, &b.points),
+        (Part::Polygon(p), Part::Rect(r)) | (Part::Rect(r), Part::Polygon(p)) => {
+            polygons_intersect(&p.points, &corners(r))
+        }
+        (Part::Polygon(p), Part::Circle(c)) | (Part::Circle(c), Part::Polygon(p)) => {
+            circle_intersects_polygon(c, p)
+        }
+    }
+}
+
+//Separating axis test again, this time keeping the axis the parts overlap
+//least along. Circles add the axis towards the nearest corner or center of
+//the other part, which is the only curved direction that can separate them
+fn parts_contact(a: Part, b: Part) -> Option<Manifold> {
+    if !parts_intersect(a, b) {
+        return None;
+    }
+    let mut axes: Vec<(f64, f64)> = Vec::new();
+    for part in [a, b] {
+        let corners = part.corners();
+        axes.extend(
+            edges(&corners).map(|(from, to)| (-(to - from).y() as f64, (to - from).x() as f64)),
+        );
+    }
+    for (circle, other) in [(a, b), (b, a)] {
+        if let Part::Circle(c) = circle {
+            let center = Point::new(c.x, c.y);
+            let nearest = match other {
+                Part::Circle(other) => Some(Point::new(other.x, other.y)),
+                _ => other
+                    .corners()
+                    .into_iter()
+                    .min_by_key(|&corner| distance_squared(center, corner)),
+            };
+            if let Some(nearest) = nearest {
+                axes.push(((nearest - center).x() as f64, (nearest - center).y() as f64));
+            }
+        }
+    }
+
+    //Concentric circles have no way out, so they go up
+    axes.retain(|&(x, y)| x != 0.0 || y != 0.0);
+    if axes.is_empty() {
+        axes.push((0.0, -1.0));
+    }
+
+    let mut best = ((0.0, 0.0), f64::INFINITY);
+    for (x, y) in axes {
+        let length = x.hypot(y);
+        let axis = (x / length, y / length);
+        let (min_a, max_a) = a.project(axis);
+        let (min_b, max_b) = b.project(axis);
+        //Out past B's far side, or back past its near side
+        for (normal, depth) in [(axis, max_b - min_a), ((-axis.0, -axis.1), max_a - min_b)] {
+            if depth < best.1 {
+                best = (normal, depth);
+            }
+        }
+    }
+    let ((x, y), depth) = best;
+    Some(Manifold {
+        normal: FPoint::new(x as f32, y as f32),
+        depth: depth.max(0.0) as f32,
+    })
+}
+
+impl Part<'_> {
+    //Corners of a box or polygon; circles have none
+    fn corners(&self) -> Vec<Point> {
+        match self {
+            Part::Rect(rect) => corners(*rect).to_vec(),
+            Part::Circle(_) => Vec::new(),
+            Part::Polygon(polygon) => polygon.points.clone(),
+        }
+    }
+
+    fn project(&self, axis: (f64, f64)) -> (f64, f64) {
+        let along = |x: i32, y: i32| x as f64 * axis.0 + y as f64 * axis.1;
+        match self {
+            Part::Circle(c) => {
+                let center = along(c.x, c.y);
+                (center - c.r as f64, center + c.r as f64)
+            }
+            _ => bounds(self.corners().iter().map(|p| along(p.x(), p.y()))),
+        }
+    }
+}
+
+//Time of impact and the normal of what was hit
+type Hit = (f64, (f64, f64));
+
+fn parts_sweep(a: Part, motion: (f64, f64), b: Part) -> Option<Hit> {
+    if parts_intersect(a, b) {
+        return None;
+    }
+    match (a, b) {
+        (Part::Circle(a), Part::Circle(b)) if a.r > 0 && b.r > 0 => sweep_point(
+            (a.x as f64, a.y as f64),
+            motion,
+            (b.x as f64, b.y as f64),
+            a.r as f64 + b.r as f64,
+        ),
+        (Part::Circle(_), Part::Circle(_)) => None,
+        (Part::Circle(circle), other) => sweep_circle(circle, motion, &other.corners()),
+        //Same as the polygon standing still and the circle coming the other way
+        (other, Part::Circle(circle)) => {
+            sweep_circle(circle, (-motion.0, -motion.1), &other.corners())
+                .map(|(time, (x, y))| (time, (-x, -y)))
+        }
+        (a, b) => sweep_polygons(&a.corners(), motion, &b.corners()),
+    }
+}
+
+//Separating axis test over time: along each axis the projections overlap for
+//one stretch of the move, and the shapes meet when all of those have begun
+fn sweep_polygons(a: &[Point], motion: (f64, f64), b: &[Point]) -> Option<Hit> {
+    if twice_area(a) == 0 || twice_area(b) == 0 {
+        return None;
+    }
+    let mut enter: Hit = (f64::NEG_INFINITY, (0.0, 0.0));
+    let mut exit = f64::INFINITY;
+    for (from, to) in edges(a).chain(edges(b)) {
+        let edge = to - from;
+        if edge == Point::new(0, 0) {
+            continue;
+        }
+        //Left unscaled so projections of whole pixels stay exact
+        let axis = (-edge.y() as f64, edge.x() as f64);
+        let along = |p: &Point| p.x() as f64 * axis.0 + p.y() as f64 * axis.1;
+        let (min_a, max_a) = bounds(a.iter().map(along));
+        let (min_b, max_b) = bounds(b.iter().map(along));
+        let speed = motion.0 * axis.0 + motion.1 * axis.1;
+        if speed == 0.0 {
+            if max_a <= min_b || max_b <= min_a {
+                return None;
+            }
+            continue;
+        }
+        let (start, end) = if speed > 0.0 {
+            ((min_b - max_a) / speed, (max_b - min_a) / speed)
+        } else {
+            ((max_b - min_a) / speed, (min_b - max_a) / speed)
+        };
+        if start > enter.0 {
+            //Back the way it came along the axis
+            let length = axis.0.hypot(axis.1) * speed.signum();
+            enter = (start, (-axis.0 / length, -axis.1 / length));
+        }
+        exit = exit.min(end);
+    }
+    (enter.0 >= 0.0 && enter.0 < 1.0 && enter.0 < exit).then_some(enter)
+}
+
+//A circle runs into a polygon when its center comes within its radius: onto
+//an edge pushed out by the radius, or onto a circle around a corner
+fn sweep_circle(circle: Circle, motion: (f64, f64), corners: &[Point]) -> Option<Hit> {
+    let area = twice_area(corners);
+    if circle.r <= 0 || area == 0 {
+        return None;
+    }
+    let r = circle.r as f64;
+    let center = (circle.x as f64, circle.y as f64);
+    let edge_hits = edges(corners).filter_map(|(from, to)| {
+        let edge = ((to - from).x() as f64, (to - from).y() as f64);
+        let length_squared = edge.0 * edge.0 + edge.1 * edge.1;
+        if length_squared == 0.0 {
+            return None;
+        }
+        //Gap and speed are both scaled by the edge's length, which cancels
+        let length = length_squared.sqrt();
+        let outward = if area > 0 {
+            (edge.1, -edge.0)
+        } else {
+            (-edge.1, edge.0)
+        };
+        let to_center = (center.0 - from.x() as f64, center.1 - from.y() as f64);
+        let gap = to_center.0 * outward.0 + to_center.1 * outward.1 - r * length;
+        let speed = motion.0 * outward.0 + motion.1 * outward.1;
+        if gap < 0.0 || speed >= 0.0 {
+            return None;
+        }
+        let time = gap / -speed;
+        //Where along the edge the center meets it
+        let at = (to_center.0 + motion.0 * time, to_center.1 + motion.1 * time);
+        let across = (at.0 * edge.0 + at.1 * edge.1) / length_squared;
+        let normal = (outward.0 / length, outward.1 / length);
+        (0.0..=1.0).contains(&across).then_some((time, normal))
+    });
+    let corner_hits = corners.iter().filter_map(|corner| {
+        sweep_point(center, motion, (corner.x() as f64, corner.y() as f64), r)
+    });
+    edge_hits
+        .chain(corner_hits)
+        .filter(|&(time, _)| time < 1.0)
+        .min_by(|x, y| x.0.total_cmp(&y.0))
+}
+
+//When a point moving by `motion` comes within `radius` of `target`
+fn sweep_point(
+    from: (f64, f64),
+    motion: (f64, f64),
+    target: (f64, f64),
+    radius: f64,
+) -> Option<Hit> {
+    let away = (from.0 - target.0, from.1 - target.1);
+    let speed_squared = motion.0 * motion.0 + motion.1 * motion.1;
+    let closing = away.0 * motion.0 + away.1 * motion.1;
+    if speed_squared == 0.0 || closing >= 0.0 {
+        return None;
+    }
+    let outside = away.0 * away.0 + away.1 * away.1 - radius * radius;
+    //Only grazing the edge of the radius isn't running into it
+    let discriminant = closing * closing - speed_squared * outside;
+    if discriminant <= 0.0 {
+        return None;
+    }
+    let time = ((-closing - discriminant.sqrt()) / speed_squared).max(0.0);
+    if time >= 1.0 {
+        return None;
+    }
+    let hit = (away.0 + motion.0 * time, away.1 + motion.1 * time);
+    let length = hit.0.hypot(hit.1);
+    Some((time, (hit.0 / length, hit.1 / length)))
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    })
+}
+
+fn rects_intersect(a: Rect, b: Rect) -> bool {
+    //If no sides from A are outside of B
+    !(a.bottom() <= b.top()
+        || a.top() >= b.bottom()
+        || a.right() <= b.left()
+        || a.left() >= b.right())
+}
+
+fn circles_intersect(a: Circle, b: Circle) -> bool {
+    if a.r <= 0 || b.r <= 0 {
+        return false;
+    }
+    //If the distance between the centers is less than the sum of the radii
+    let total_radius = a.r as i64 + b.r as i64;
+    distance_squared(Point::new(a.x, a.y), Point::new(b.x, b.y)) < total_radius * total_radius
+}
+
+fn circle_intersects_rect(circle: Circle, rect: Rect) -> bool {
+    //Closest point of the box to the center
+    let closest = Point::new(
+        circle.x.clamp(rect.left(), rect.right()),
+        circle.y.clamp(rect.top(), rect.bottom()),
+    );
+    let r = circle.r.max(0) as i64;
+    distance_squared(Point::new(circle.x, circle.y), closest) < r * r
+}
+
+fn circle_intersects_polygon(circle: Circle, polygon: &Polygon) -> bool {
+    if circle.r <= 0 {
+        return false;
+    }
+    let center = Point::new(circle.x, circle.y);
+    polygon.covers(center)
+        || edges(&polygon.points).any(|(a, b)| segment_within(center, a, b, circle.r))
+}
+
+//Whether the segment from `a` to `b` comes closer than `r` to `p`
+fn segment_within(p: Point, a: Point, b: Point, r: i32) -> bool {
+    let r_squared = r as i128 * r as i128;
+    let edge = b - a;
+    let to_p = p - a;
+    let along = dot(to_p, edge) as i128;
+    let length_squared = dot(edge, edge) as i128;
+    if along <= 0 || length_squared == 0 {
+        (distance_squared(p, a) as i128) < r_squared
+    } else if along >= length_squared {
+        (distance_squared(p, b) as i128) < r_squared
+    } else {
+        //Squared distance to the line, scaled by the edge's squared length
+        let to_p_squared = dot(to_p, to_p) as i128;
+        to_p_squared * length_squared - along * along < r_squared * length_squared
+    }
+}
+
+//Separating axis test: convex shapes overlap unless some edge's normal
+//separates them
+fn polygons_intersect(a: &[Point], b: &[Point]) -> bool {
+    if twice_area(a) == 0 || twice_area(b) == 0 {
+        return false;
+    }
+    !edges(a).chain(edges(b)).any(|(from, to)| {
+        let edge = to - from;
+        if edge == Point::new(0, 0) {
+            return false;
+        }
+        let normal = Point::new(-edge.y(), edge.x());
+        let (min_a, max_a) = project(a, normal);
+        let (min_b, max_b) = project(b, normal);
+        max_a <= min_b || max_b <= min_a
+    })
+}
+
+fn project(points: &[Point], axis: Point) -> (i64, i64) {
+    points
+        .iter()
+        .map(|&p| dot(p, axis))
+        .fold((i64::MAX, i64::MIN), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+fn corners(rect: Rect) -> [Point; 4] {
+    [
+        rect.top_left(),
+        rect.top_right(),
+        rect.bottom_right(),
+        rect.bottom_left(),
+    ]
+}
+
+fn offset_rect(mut rect: Rect, dx: i32, dy: i32) -> Rect {
+    rect.offset(dx, dy);
+    rect
+}
+
+//Each edge as a pair of corners, closing the loop
+fn edges(points: &[Point]) -> impl Iterator<Item = (Point, Point)> + Clone + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+fn twice_area(points: &[Point]) -> i64 {
+    edges(points).map(|(a, b)| cross(a, b)).sum()
+}
+
+fn dot(a: Point, b: Point) -> i64 {
+    a.x() as i64 * b.x() as i64 + a.y() as i64 * b.y() as i64
+}
+
+fn cross(a: Point, b: Point) -> i64 {
+    a.x() as i64 * b.y() as i64 - a.y() as i64 * b.x() as i64
+}
+
+fn distance_squared(a: Point, b: Point) -> i64 {
+    let d = a - b;
+    dot(d, d)
+}
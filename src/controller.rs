@@ -0,0 +1,51 @@
+use crate::error::{Error, Result};
+use sdl2::controller::GameController;
+use sdl2::event::Event;
+use sdl2::GameControllerSubsystem;
+use std::collections::HashMap;
+
+/// Keeps every connected game controller open so SDL reports its events.
+///
+/// Controllers are opened on `ControllerDeviceAdded`, which SDL also sends
+/// for pads already plugged in at startup, and closed on
+/// `ControllerDeviceRemoved`.
+pub struct Controllers {
+    subsystem: GameControllerSubsystem,
+    //Keyed by joystick instance id, as used by controller events
+    open: HashMap<u32, GameController>,
+}
+
+impl Controllers {
+    pub fn new(subsystem: GameControllerSubsystem) -> Self {
+        Self {
+            subsystem,
+            open: HashMap::new(),
+        }
+    }
+
+    pub fn handle_event(&mut self, event: &Event) -> Result<()> {
+        match *event {
+            //`which` is a device index here, not an instance id
+            Event::ControllerDeviceAdded { which, .. } => {
+                let controller = self
+                    .subsystem
+                    .open(which)
+                    .map_err(|e| Error::init("game controller", e))?;
+                self.open.insert(controller.instance_id(), controller);
+            }
+            Event::ControllerDeviceRemoved { which, .. } => {
+                self.open.remove(&which);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.open.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.open.is_empty()
+    }
+}
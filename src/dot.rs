@@ -0,0 +1,230 @@
+use crate::collision::{contact, sweep, Impact, Shape};
+use crate::error::Result;
+use crate::input::Input;
+use crate::render_queue::{Depth, RenderQueue};
+use crate::texture::LTexture;
+use sdl2::rect::{FPoint, Rect};
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+//Times a dot slides along what it ran into before it stops for the tick
+const MAX_SLIDES: usize = 3;
+
+//Times a dot is pushed out of what it ended up in before giving up on the move
+const MAX_PUSHES: usize = 4;
+
+//The soonest `collider` moving by `motion` runs into any of `obstacles`
+fn first_impact(collider: &Shape, motion: FPoint, obstacles: &[Shape]) -> Option<Impact> {
+    obstacles
+        .iter()
+        .filter_map(|obstacle| sweep(collider, motion, obstacle))
+        .min_by(|a, b| a.time.total_cmp(&b.time))
+}
+
+//Rounds a push away from zero to whole pixels, ignoring float error that
+//would add a pixel of gap
+fn whole_pixels(push: f32) -> f32 {
+    (push.abs() - 1e-3).ceil().max(0.0).copysign(push)
+}
+
+pub struct Dot {
+    pub x_pos: f32,
+    pub y_pos: f32,
+    //Position before the last move, for interpolated rendering
+    prev_x: f32,
+    prev_y: f32,
+    x_vel: f32,
+    y_vel: f32,
+    dot_vel: f32,
+}
+
+impl Dot {
+    pub const DOT_WIDTH: u32 = 20;
+    pub const DOT_HEIGHT: u32 = 20;
+
+    /// Creates a dot at `(x, y)` that moves `dot_vel` pixels per second while a movement action is held.
+    pub fn new(x: f32, y: f32, dot_vel: f32) -> Self {
+        Self {
+            x_pos: x,
+            y_pos: y,
+            prev_x: x,
+            prev_y: y,
+            x_vel: 0.0,
+            y_vel: 0.0,
+            dot_vel,
+        }
+    }
+
+    /// Sets the velocity from the `move_x` and `move_y` axes.
+    pub fn handle_input(&mut self, input: &Input) {
+        self.x_vel = input.axis("move_x") * self.dot_vel;
+        self.y_vel = input.axis("move_y") * self.dot_vel;
+    }
+
+    pub fn hash_state(&self, state: &mut dyn Hasher) {
+        for value in [self.x_pos, self.y_pos, self.x_vel, self.y_vel] {
+            state.write_u32(value.to_bits());
+        }
+    }
+
+    /// Bounding box of the dot at its current position, rounded to whole pixels.
+    pub fn collider(&self) -> Rect {
+        Rect::new(
+            self.x_pos.round() as i32,
+            self.y_pos.round() as i32,
+            Self::DOT_WIDTH,
+            Self::DOT_HEIGHT,
+        )
+    }
+
+    /// Position `alpha` of the way from before the last move to now.
+    pub fn position_at(&self, alpha: f32) -> FPoint {
+        FPoint::new(
+            self.prev_x + (self.x_pos - self.prev_x) * alpha,
+            self.prev_y + (self.y_pos - self.prev_y) * alpha,
+        )
+    }
+
+    /// Moves the dot for `dt`, keeping it inside `bounds`.
+    pub fn move_position(&mut self, bounds: Rect, dt: Duration) {
+        self.move_position_with(bounds, dt, &[], |dot| Shape::Aabb(dot.collider()));
+    }
+
+    /// Moves the dot for `dt` as `collider`, stopping where it first runs
+    /// into one of `obstacles` and sliding along it for the rest of the move,
+    /// so however fast the dot goes it can't pass through anything. If it
+    /// still ends up inside something it is pushed back out along the
+    /// contact normal, coming to rest flush against it.
+    pub fn move_position_with<F>(
+        &mut self,
+        bounds: Rect,
+        dt: Duration,
+        obstacles: &[Shape],
+        collider: F,
+    ) where
+        F: Fn(&Dot) -> Shape,
+    {
+        let dt = dt.as_secs_f32();
+        self.prev_x = self.x_pos;
+        self.prev_y = self.y_pos;
+
+        //Where the dot would go with nothing in the way, stopping at the
+        //edges of `bounds`
+        let (target_x, target_y) = Self::inside(
+            bounds,
+            self.x_pos + self.x_vel * dt,
+            self.y_pos + self.y_vel * dt,
+        );
+
+        //The collider is rounded to whole pixels, so sweep it from where it
+        //was drawn
+        let mut motion = FPoint::new(target_x - self.x_pos.round(), target_y - self.y_pos.round());
+        let mut slides = 0;
+        while let Some(impact) = first_impact(&collider(self), motion, obstacles) {
+            self.x_pos = self.x_pos.round() + motion.x * impact.time;
+            self.y_pos = self.y_pos.round() + motion.y * impact.time;
+
+            //The rest of the move, less the part going into what was hit
+            let left = 1.0 - impact.time;
+            motion = FPoint::new(motion.x * left, motion.y * left);
+            let into = motion.x * impact.normal.x + motion.y * impact.normal.y;
+            if into < 0.0 {
+                motion.x -= impact.normal.x * into;
+                motion.y -= impact.normal.y * into;
+            }
+
+            slides += 1;
+            if slides == MAX_SLIDES {
+                motion = FPoint::new(0.0, 0.0);
+                break;
+            }
+        }
+        if slides == 0 {
+            self.x_pos = target_x;
+            self.y_pos = target_y;
+        } else {
+            self.x_pos = self.x_pos.round() + motion.x;
+            self.y_pos = self.y_pos.round() + motion.y;
+            (self.x_pos, self.y_pos) = Self::inside(bounds, self.x_pos, self.y_pos);
+        }
+
+        let deepest_contact = |dot: &Dot| {
+            let shape = collider(dot);
+            obstacles
+                .iter()
+                .filter_map(|obstacle| contact(&shape, obstacle))
+                .max_by(|a, b| a.depth.total_cmp(&b.depth))
+        };
+        for _ in 0..MAX_PUSHES {
+            let Some(manifold) = deepest_contact(self) else {
+                return;
+            };
+            //Push it out by whole pixels from where it was drawn
+            let push = manifold.translation();
+            let (out_x, out_y) = (whole_pixels(push.x), whole_pixels(push.y));
+            if out_x != 0.0 {
+                self.x_pos = self.x_pos.round() + out_x;
+            }
+            if out_y != 0.0 {
+                self.y_pos = self.y_pos.round() + out_y;
+            }
+            (self.x_pos, self.y_pos) = Self::inside(bounds, self.x_pos, self.y_pos);
+        }
+
+        //Wedged between things that push it back into each other
+        if deepest_contact(self).is_some() {
+            self.x_pos = self.prev_x;
+            self.y_pos = self.prev_y;
+        }
+    }
+
+    //The closest spot to (`x`, `y`) that keeps the dot inside `bounds`
+    fn inside(bounds: Rect, x: f32, y: f32) -> (f32, f32) {
+        (
+            x.clamp(
+                bounds.left() as f32,
+                (bounds.right() - Self::DOT_WIDTH as i32) as f32,
+            ),
+            y.clamp(
+                bounds.top() as f32,
+                (bounds.bottom() - Self::DOT_HEIGHT as i32) as f32,
+            ),
+        )
+    }
+
+    //Shows the dot on the screen relative to the camera, `alpha` of the way through its last move
+    pub fn render(
+        &self,
+        dot_texture: &LTexture,
+        canvas: &mut WindowCanvas,
+        cam_x: i32,
+        cam_y: i32,
+        alpha: f32,
+    ) -> Result<()> {
+        let position = self.position_at(alpha);
+        dot_texture.render(
+            canvas,
+            position.x.round() as i32 - cam_x,
+            position.y.round() as i32 - cam_y,
+            None,
+        )
+    }
+
+    //Queues the dot where `render` would draw it, sorted on `layer` by how far down it is
+    pub fn queue<'t>(
+        &self,
+        queue: &mut RenderQueue<'t>,
+        dot_texture: &'t LTexture<'t>,
+        layer: i32,
+        cam_x: i32,
+        cam_y: i32,
+        alpha: f32,
+    ) {
+        let position = self.position_at(alpha);
+        let x = position.x.round() as i32 - cam_x;
+        let y = position.y.round() as i32 - cam_y;
+        let dest = Rect::new(x, y, Self::DOT_WIDTH, Self::DOT_HEIGHT);
+        queue.sprite(Depth::y_sorted(layer, dest), dot_texture, x, y, None);
+    }
+}
@@ -0,0 +1,145 @@
+use std::error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Underlying cause of an [`Error`], usually the message reported by SDL.
+pub type Source = Box<dyn error::Error + Send + Sync>;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A resource file does not exist.
+    AssetNotFound { path: PathBuf },
+    /// A resource file exists but could not be decoded.
+    Decode { path: PathBuf, source: Source },
+    /// An image could not be written to disk.
+    Encode { path: PathBuf, source: Source },
+    /// SDL or one of its subsystems (video, window, canvas, ttf, timer...) failed to start.
+    Init {
+        subsystem: &'static str,
+        source: Source,
+    },
+    /// A font could not be loaded, or text could not be rendered with it.
+    Font {
+        path: Option<PathBuf>,
+        source: Source,
+    },
+    /// The audio device could not be opened, or a sound could not be loaded or played.
+    Mixer {
+        path: Option<PathBuf>,
+        source: Source,
+    },
+    /// A renderer or texture operation failed.
+    Render { source: Source },
+    /// A replayed lesson's state stopped matching its recording.
+    Diverged { frame: usize },
+    /// Bytes read back as a recording aren't one, or are cut short.
+    BadRecording { reason: String },
+    /// An atlas was asked for a region it was not built with.
+    MissingRegion { name: String },
+    /// A sprite sheet was asked for an animation it has no tag for.
+    MissingAnimation { name: String },
+    /// A handle was used after its asset was unloaded from the cache.
+    Unloaded { path: PathBuf },
+}
+
+impl Error {
+    pub fn decode(path: impl AsRef<Path>, source: impl Into<Source>) -> Self {
+        Self::Decode {
+            path: path.as_ref().to_path_buf(),
+            source: source.into(),
+        }
+    }
+
+    pub fn encode(path: impl AsRef<Path>, source: impl Into<Source>) -> Self {
+        Self::Encode {
+            path: path.as_ref().to_path_buf(),
+            source: source.into(),
+        }
+    }
+
+    pub fn init(subsystem: &'static str, source: impl Into<Source>) -> Self {
+        Self::Init {
+            subsystem,
+            source: source.into(),
+        }
+    }
+
+    pub fn font(source: impl Into<Source>) -> Self {
+        Self::Font {
+            path: None,
+            source: source.into(),
+        }
+    }
+
+    pub fn mixer(source: impl Into<Source>) -> Self {
+        Self::Mixer {
+            path: None,
+            source: source.into(),
+        }
+    }
+
+    pub fn render(source: impl Into<Source>) -> Self {
+        Self::Render {
+            source: source.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssetNotFound { path } => write!(f, "asset not found: {}", path.display()),
+            Self::Decode { path, .. } => write!(f, "could not decode {}", path.display()),
+            Self::Encode { path, .. } => write!(f, "could not write {}", path.display()),
+            Self::Init { subsystem, .. } => write!(f, "could not initialize {subsystem}"),
+            Self::Font {
+                path: Some(path), ..
+            } => write!(f, "could not load font {}", path.display()),
+            Self::Font { path: None, .. } => write!(f, "could not render text"),
+            Self::Mixer {
+                path: Some(path), ..
+            } => write!(f, "could not load sound {}", path.display()),
+            Self::Mixer { path: None, .. } => write!(f, "audio error"),
+            Self::Render { .. } => write!(f, "render error"),
+            Self::Diverged { frame } => {
+                write!(f, "replay diverged from the recording at frame {frame}")
+            }
+            Self::BadRecording { reason } => write!(f, "bad recording: {reason}"),
+            Self::MissingRegion { name } => write!(f, "no atlas region named {name:?}"),
+            Self::MissingAnimation { name } => write!(f, "no animation tagged {name:?}"),
+            Self::Unloaded { path } => write!(f, "{} was unloaded", path.display()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::AssetNotFound { .. }
+            | Self::Diverged { .. }
+            | Self::BadRecording { .. }
+            | Self::MissingRegion { .. }
+            | Self::MissingAnimation { .. }
+            | Self::Unloaded { .. } => None,
+            Self::Decode { source, .. }
+            | Self::Encode { source, .. }
+            | Self::Init { source, .. }
+            | Self::Font { source, .. }
+            | Self::Mixer { source, .. }
+            | Self::Render { source } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// Fails with [`Error::AssetNotFound`] if nothing exists at `path`.
+pub fn check_asset(path: &Path) -> Result<()> {
+    if path.exists() {
+        Ok(())
+    } else {
+        Err(Error::AssetNotFound {
+            path: path.to_path_buf(),
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use crate::error::{check_asset, Error, Result};
+use sdl2::ttf::{Font, Sdl2TtfContext};
+use std::path::Path;
+
+pub fn init_ttf() -> Result<Sdl2TtfContext> {
+    sdl2::ttf::init().map_err(|e| Error::init("sdl2_ttf", e))
+}
+
+pub fn load_font<'ttf>(
+    ttf_context: &'ttf Sdl2TtfContext,
+    path: impl AsRef<Path>,
+    point_size: u16,
+) -> Result<Font<'ttf, 'static>> {
+    let path = path.as_ref();
+    check_asset(path)?;
+    ttf_context
+        .load_font(path, point_size)
+        .map_err(|e| Error::Font {
+            path: Some(path.to_path_buf()),
+            source: e.into(),
+        })
+}
@@ -0,0 +1,43 @@
+use crate::clock::Clock;
+use crate::timer::LTimer;
+
+//Anything above this is the near-zero elapsed time of the first frames, not a real rate
+const MAX_PLAUSIBLE_FPS: f32 = 2000000.0;
+
+/// Average frames per second since the counter was started.
+pub struct FpsCounter {
+    timer: LTimer,
+    counted_frames: u32,
+}
+
+impl FpsCounter {
+    /// Creates a counter that starts timing immediately.
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        let mut timer = LTimer::new(clock);
+        timer.start();
+        Self {
+            timer,
+            counted_frames: 0,
+        }
+    }
+
+    /// Average over the frames counted so far, or 0 while too little time has
+    /// passed to tell.
+    pub fn average(&self) -> f32 {
+        let avg_fps = self.counted_frames as f32 / self.timer.get_ticks().as_secs_f32();
+        //Also catches 0 / 0 on the very first frame
+        if avg_fps.is_nan() || avg_fps > MAX_PLAUSIBLE_FPS {
+            0.0
+        } else {
+            avg_fps
+        }
+    }
+
+    pub fn count_frame(&mut self) {
+        self.counted_frames += 1;
+    }
+
+    pub fn counted_frames(&self) -> u32 {
+        self.counted_frames
+    }
+}
@@ -0,0 +1,245 @@
+//! TrueType text drawn from a texture of cached glyphs, so text that changes
+//! every frame doesn't need a new surface and texture every frame.
+
+use crate::error::{Error, Result};
+use crate::pixels::{Pixels, TRANSPARENT};
+use crate::render_queue::GlyphSource;
+use crate::texture::LTexture;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::ttf::Font;
+use sdl2::video::WindowContext;
+use std::collections::HashMap;
+
+/// Width and starting height of a new atlas. It grows taller as needed.
+pub const DEFAULT_ATLAS_SIZE: u32 = 256;
+/// How tall an atlas may grow.
+pub const MAX_ATLAS_HEIGHT: u32 = 4096;
+
+//Gap between glyphs so scaled text doesn't pick up its neighbours
+const PADDING: u32 = 1;
+
+#[derive(Clone, Copy, Debug)]
+struct Glyph {
+    clip: Rect,
+    advance: i32,
+}
+
+/// Glyphs of one font, at the size and style it was set to, rasterized the
+/// first time they are needed. Glyphs are drawn white and tinted with
+/// [`GlyphAtlas::set_color`].
+pub struct GlyphAtlas<'a> {
+    texture_creator: &'a TextureCreator<WindowContext>,
+    font: Font<'a, 'static>,
+    texture: LTexture<'a>,
+    glyphs: HashMap<char, Glyph>,
+    //Where the next glyph goes, packed in rows
+    cursor: Point,
+    row_height: u32,
+}
+
+impl<'a> GlyphAtlas<'a> {
+    pub fn new(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        font: Font<'a, 'static>,
+    ) -> Result<Self> {
+        Self::with_size(
+            texture_creator,
+            font,
+            DEFAULT_ATLAS_SIZE,
+            DEFAULT_ATLAS_SIZE,
+        )
+    }
+
+    pub fn with_size(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        font: Font<'a, 'static>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self> {
+        let texture =
+            LTexture::from_pixels(texture_creator, Pixels::new(width, height, TRANSPARENT))?;
+        Ok(Self {
+            texture_creator,
+            font,
+            texture,
+            glyphs: HashMap::new(),
+            cursor: Point::new(0, 0),
+            row_height: 0,
+        })
+    }
+
+    /// The font the glyphs come from, e.g. for rendering fixed text once.
+    pub fn font(&self) -> &Font<'a, 'static> {
+        &self.font
+    }
+
+    /// How many different characters have been rasterized.
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        (self.texture.width(), self.texture.height())
+    }
+
+    pub fn set_color(&mut self, r: u8, g: u8, b: u8) {
+        self.texture.set_color(r, g, b);
+    }
+
+    /// Rasterizes any characters of `text` the atlas doesn't have yet. Text
+    /// is only drawn with glyphs that were prepared.
+    pub fn prepare(&mut self, text: &str) -> Result<()> {
+        let mut added = Vec::new();
+        for c in text.chars() {
+            if c == '\n' || self.glyphs.contains_key(&c) || added.iter().any(|(a, _)| *a == c) {
+                continue;
+            }
+            let surface = self
+                .font
+                .render_char(c)
+                .blended(Color::RGB(255, 255, 255))
+                .map_err(Error::font)?;
+            added.push((c, Pixels::from_surface(&surface)?));
+        }
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        //A string that fails partway gives back the room its glyphs were packed into
+        let (cursor, row_height) = (self.cursor, self.row_height);
+        let packed = self.pack(&added);
+        if packed.is_err() {
+            self.cursor = cursor;
+            self.row_height = row_height;
+        }
+        packed
+    }
+
+    //Places and uploads `added`, recording the glyphs only once all of them are in the texture
+    fn pack(&mut self, added: &[(char, Pixels)]) -> Result<()> {
+        let mut places = Vec::with_capacity(added.len());
+        for (_, pixels) in added {
+            places.push(self.place(pixels.width(), pixels.height())?);
+        }
+        let mut atlas = self.texture.lock()?;
+        for ((_, pixels), at) in added.iter().zip(&places) {
+            atlas.copy_from(pixels, at.x(), at.y());
+        }
+        atlas.unlock()?;
+
+        for ((c, pixels), at) in added.iter().zip(places) {
+            let advance = self
+                .font
+                .find_glyph_metrics(*c)
+                .map_or(pixels.width() as i32, |metrics| metrics.advance);
+            self.glyphs.insert(
+                *c,
+                Glyph {
+                    clip: Rect::new(at.x(), at.y(), pixels.width(), pixels.height()),
+                    advance,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// The width of the widest line of `text` and the height of all of them.
+    pub fn measure(&self, text: &str) -> (u32, u32) {
+        let (mut x, mut width, mut lines) = (0, 0, 1);
+        for c in text.chars() {
+            if c == '\n' {
+                x = 0;
+                lines += 1;
+                continue;
+            }
+            x += self.advance(c);
+            width = width.max(x);
+        }
+        let height = self.font.height() + (lines - 1) * self.font.recommended_line_spacing();
+        (width.max(0) as u32, height.max(0) as u32)
+    }
+
+    /// Draws `text` with its top left corner at (`x`, `y`), skipping glyphs
+    /// that weren't [prepared](GlyphAtlas::prepare).
+    pub fn render(&self, canvas: &mut WindowCanvas, x: i32, y: i32, text: &str) -> Result<()> {
+        let mut result = Ok(());
+        self.place_glyphs(text, &mut |clip, at| {
+            if result.is_ok() {
+                result = self
+                    .texture
+                    .render(canvas, x + at.x(), y + at.y(), Some(clip));
+            }
+        });
+        result
+    }
+
+    fn advance(&self, c: char) -> i32 {
+        match self.glyphs.get(&c) {
+            Some(glyph) => glyph.advance,
+            None => self
+                .font
+                .find_glyph_metrics(c)
+                .map_or(0, |metrics| metrics.advance),
+        }
+    }
+
+    //Finds room for a glyph, growing the atlas if it is full
+    fn place(&mut self, width: u32, height: u32) -> Result<Point> {
+        let atlas_width = self.texture.width();
+        if width > atlas_width {
+            return Err(Error::render(format!(
+                "a {width} pixel wide glyph does not fit a {atlas_width} pixel wide atlas"
+            )));
+        }
+        if self.cursor.x() as u32 + width > atlas_width {
+            self.cursor = Point::new(0, self.cursor.y() + (self.row_height + PADDING) as i32);
+            self.row_height = 0;
+        }
+        while self.cursor.y() as u32 + height > self.texture.height() {
+            self.grow()?;
+        }
+
+        let at = self.cursor;
+        self.cursor = self.cursor.offset((width + PADDING) as i32, 0);
+        self.row_height = self.row_height.max(height);
+        Ok(at)
+    }
+
+    fn grow(&mut self) -> Result<()> {
+        let (width, height) = self.size();
+        if height >= MAX_ATLAS_HEIGHT {
+            return Err(Error::render("the glyph atlas is full"));
+        }
+        let mut pixels = Pixels::new(width, (height * 2).clamp(1, MAX_ATLAS_HEIGHT), TRANSPARENT);
+        if let Some(old) = self.texture.pixels() {
+            pixels.copy_from(old, 0, 0);
+        }
+        let mut texture = LTexture::from_pixels(self.texture_creator, pixels)?;
+        texture.copy_modulation(&self.texture);
+        self.texture = texture;
+        Ok(())
+    }
+}
+
+impl GlyphSource for GlyphAtlas<'_> {
+    fn glyph_texture(&self) -> &LTexture<'_> {
+        &self.texture
+    }
+
+    fn place_glyphs(&self, text: &str, place: &mut dyn FnMut(Rect, Point)) {
+        let (mut x, mut y) = (0, 0);
+        for c in text.chars() {
+            if c == '\n' {
+                x = 0;
+                y += self.font.recommended_line_spacing();
+                continue;
+            }
+            if let Some(glyph) = self.glyphs.get(&c) {
+                place(glyph.clip, Point::new(x, y));
+            }
+            x += self.advance(c);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Runs lessons without a visible window so their output can be compared
+//! against golden images.
+
+use crate::app::{self, create_canvas, Flow};
+use crate::assets::AssetCache;
+pub use crate::capture::Snapshot;
+use crate::error::{Error, Result};
+use crate::font::init_ttf;
+use crate::input::{Bindings, Input, DEFAULT_BINDINGS};
+use crate::lesson::{Context, LessonInfo};
+use crate::timestep::FixedTimestep;
+use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod, Scancode};
+use sdl2::mouse::{MouseButton, MouseState};
+use sdl2::render::WindowCanvas;
+
+/// Events to feed a lesson, each tagged with the frame it arrives on.
+#[derive(Default)]
+pub struct Script {
+    events: Vec<(u32, Event)>,
+}
+
+impl Script {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn event(mut self, frame: u32, event: Event) -> Self {
+        self.events.push((frame, event));
+        self
+    }
+
+    pub fn key_down(self, frame: u32, keycode: Keycode) -> Self {
+        self.event(
+            frame,
+            Event::KeyDown {
+                timestamp: 0,
+                window_id: 0,
+                keycode: Some(keycode),
+                scancode: Scancode::from_keycode(keycode),
+                keymod: Mod::NOMOD,
+                repeat: false,
+            },
+        )
+    }
+
+    pub fn key_up(self, frame: u32, keycode: Keycode) -> Self {
+        self.event(
+            frame,
+            Event::KeyUp {
+                timestamp: 0,
+                window_id: 0,
+                keycode: Some(keycode),
+                scancode: Scancode::from_keycode(keycode),
+                keymod: Mod::NOMOD,
+                repeat: false,
+            },
+        )
+    }
+
+    /// Presses `keycode` on `frame` and releases it `held` frames later.
+    pub fn key_press(self, frame: u32, keycode: Keycode, held: u32) -> Self {
+        self.key_down(frame, keycode).key_up(frame + held, keycode)
+    }
+
+    pub fn mouse_motion(self, frame: u32, x: i32, y: i32) -> Self {
+        self.event(
+            frame,
+            Event::MouseMotion {
+                timestamp: 0,
+                window_id: 0,
+                which: 0,
+                mousestate: MouseState::from_sdl_state(0),
+                x,
+                y,
+                xrel: 0,
+                yrel: 0,
+            },
+        )
+    }
+
+    pub fn mouse_button_down(self, frame: u32, x: i32, y: i32) -> Self {
+        self.event(
+            frame,
+            Event::MouseButtonDown {
+                timestamp: 0,
+                window_id: 0,
+                which: 0,
+                mouse_btn: MouseButton::Left,
+                clicks: 1,
+                x,
+                y,
+            },
+        )
+    }
+
+    pub fn mouse_button_up(self, frame: u32, x: i32, y: i32) -> Self {
+        self.event(
+            frame,
+            Event::MouseButtonUp {
+                timestamp: 0,
+                window_id: 0,
+                which: 0,
+                mouse_btn: MouseButton::Left,
+                clicks: 1,
+                x,
+                y,
+            },
+        )
+    }
+
+    pub fn text_input(self, frame: u32, text: &str) -> Self {
+        self.event(
+            frame,
+            Event::TextInput {
+                timestamp: 0,
+                window_id: 0,
+                text: text.to_string(),
+            },
+        )
+    }
+
+    /// The events that arrive on `frame`, in the order they were added.
+    pub fn events_at(&self, frame: u32) -> impl Iterator<Item = Event> + '_ {
+        self.events
+            .iter()
+            .filter(move |(f, _)| *f == frame)
+            .map(|(_, event)| event.clone())
+    }
+}
+
+/// Runs `info` for `frames` frames of one tick each on SDL's dummy video driver with a
+/// software renderer and returns what the last frame drew.
+pub fn render_lesson(info: &LessonInfo, frames: u32, script: &Script) -> Result<Snapshot> {
+    let mut last = None;
+    run_headless(info, frames, script, |frame, canvas| {
+        if frame + 1 == frames {
+            last = Some(Snapshot::read(canvas)?);
+        }
+        Ok(())
+    })?;
+    last.ok_or_else(|| Error::render(format!("{} stopped before frame {frames}", info.name)))
+}
+
+/// Runs `info` like [`render_lesson`], calling `after_frame` with the number and canvas of
+/// every frame drawn. Stops early if the lesson quits.
+pub fn run_headless(
+    info: &LessonInfo,
+    frames: u32,
+    script: &Script,
+    mut after_frame: impl FnMut(u32, &WindowCanvas) -> Result<()>,
+) -> Result<()> {
+    sdl2::hint::set("SDL_VIDEODRIVER", "dummy");
+    sdl2::hint::set("SDL_AUDIODRIVER", "dummy");
+
+    let sdl_context = sdl2::init().map_err(|e| Error::init("sdl", e))?;
+    let video_subsystem = sdl_context.video().map_err(|e| Error::init("video", e))?;
+    let mut canvas = create_canvas(&video_subsystem, info.title, true)?;
+
+    let texture_creator = canvas.texture_creator();
+    let ttf_context = init_ttf()?;
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS)?);
+    let ctx = Context {
+        texture_creator: &texture_creator,
+        ttf_context: &ttf_context,
+        input: &input,
+        assets: AssetCache::new(&texture_creator, &ttf_context),
+        video: video_subsystem,
+        timer: sdl_context.timer().map_err(|e| Error::init("timer", e))?,
+    };
+    let mut lesson = (info.create)(&ctx)?;
+
+    //Every frame runs exactly one tick so output doesn't depend on wall-clock time
+    let mut timestep = FixedTimestep::default();
+    let step = timestep.step();
+    for frame in 0..frames {
+        let events = script.events_at(frame);
+        if app::frame(
+            lesson.as_mut(),
+            &ctx,
+            &mut canvas,
+            events,
+            &mut timestep,
+            step,
+        )? != Flow::Continue
+        {
+            break;
+        }
+        after_frame(frame, &canvas)?;
+    }
+    Ok(())
+}
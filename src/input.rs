@@ -0,0 +1,310 @@
+use crate::error::{check_asset, Error, Result};
+use sdl2::controller::{Axis, Button};
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::mouse::MouseButton;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Bindings every lesson runs with.
+pub const DEFAULT_BINDINGS: &str = "resources/bindings.ron";
+
+/// Stick deflection, as a fraction of full travel, ignored unless set otherwise.
+pub const DEFAULT_DEAD_ZONE: f32 = 0.2;
+
+/// A physical input an action can be bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "BindingName")]
+pub enum Binding {
+    Key(Keycode),
+    Mouse(MouseButton),
+    Button(Button),
+}
+
+//Bindings as written in the file, by SDL name
+#[derive(Deserialize)]
+enum BindingName {
+    Key(String),
+    Mouse(String),
+    Button(String),
+}
+
+impl TryFrom<BindingName> for Binding {
+    type Error = String;
+
+    fn try_from(name: BindingName) -> Result<Self, String> {
+        match name {
+            BindingName::Key(name) => Keycode::from_name(&name)
+                .map(Binding::Key)
+                .ok_or_else(|| format!("unknown key {name:?}")),
+            BindingName::Mouse(name) => match name.as_str() {
+                "Left" => Ok(Binding::Mouse(MouseButton::Left)),
+                "Middle" => Ok(Binding::Mouse(MouseButton::Middle)),
+                "Right" => Ok(Binding::Mouse(MouseButton::Right)),
+                "X1" => Ok(Binding::Mouse(MouseButton::X1)),
+                "X2" => Ok(Binding::Mouse(MouseButton::X2)),
+                _ => Err(format!("unknown mouse button {name:?}")),
+            },
+            BindingName::Button(name) => Button::from_string(&name)
+                .map(Binding::Button)
+                .ok_or_else(|| format!("unknown controller button {name:?}")),
+        }
+    }
+}
+
+/// One direction of a controller's analog sticks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum StickAxis {
+    LeftX,
+    LeftY,
+    RightX,
+    RightY,
+}
+
+impl StickAxis {
+    //Both axes of the stick this one belongs to, with this one first
+    fn stick(self) -> (Axis, Axis) {
+        match self {
+            Self::LeftX => (Axis::LeftX, Axis::LeftY),
+            Self::LeftY => (Axis::LeftY, Axis::LeftX),
+            Self::RightX => (Axis::RightX, Axis::RightY),
+            Self::RightY => (Axis::RightY, Axis::RightX),
+        }
+    }
+}
+
+/// An axis driven by two actions, reading -1.0 while only `negative` is
+/// pressed and 1.0 while only `positive` is, plus an optional analog stick.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AxisBinding {
+    pub negative: String,
+    pub positive: String,
+    #[serde(default)]
+    pub stick: Option<StickAxis>,
+}
+
+/// Named actions and axes and the inputs that drive them.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Bindings {
+    #[serde(default)]
+    pub actions: HashMap<String, Vec<Binding>>,
+    #[serde(default)]
+    pub axes: HashMap<String, AxisBinding>,
+    /// Radial dead zone applied to sticks, in `0.0..1.0`.
+    #[serde(default = "default_dead_zone")]
+    pub dead_zone: f32,
+}
+
+fn default_dead_zone() -> f32 {
+    DEFAULT_DEAD_ZONE
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Self {
+            actions: HashMap::new(),
+            axes: HashMap::new(),
+            dead_zone: DEFAULT_DEAD_ZONE,
+        }
+    }
+}
+
+impl Bindings {
+    /// Reads bindings from a RON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let text = fs::read_to_string(path).map_err(|e| Error::decode(path, e))?;
+        Self::parse(&text).map_err(|e| Error::decode(path, e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(text)
+    }
+
+    /// The names of the keys bound to `action`, joined with slashes, for
+    /// prompts like "Press Space/W to jump". Empty if no key is bound.
+    pub fn key_names(&self, action: &str) -> String {
+        let names: Vec<_> = self
+            .bound_to(action)
+            .iter()
+            .filter_map(|binding| match binding {
+                Binding::Key(keycode) => Some(keycode.name()),
+                _ => None,
+            })
+            .collect();
+        names.join("/")
+    }
+
+    fn bound_to(&self, action: &str) -> &[Binding] {
+        self.actions.get(action).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    //Keys and mouse buttons; controller buttons are tracked per pad
+    held: HashSet<Binding>,
+    pads: HashMap<u32, Pad>,
+    //Went down since the last end_frame, even if already released again
+    pressed_this_frame: HashSet<Binding>,
+}
+
+//What one controller, by instance id, is holding
+#[derive(Default)]
+struct Pad {
+    buttons: HashSet<Button>,
+    axes: HashMap<Axis, i16>,
+}
+
+impl State {
+    fn press(&mut self, binding: Binding) {
+        self.held.insert(binding);
+        self.pressed_this_frame.insert(binding);
+    }
+}
+
+impl Pad {
+    //Deflection of `axis` in -1.0..=1.0 after the radial dead zone, rescaled so
+    //output starts at 0 on the edge of the dead zone rather than jumping
+    fn stick(&self, axis: StickAxis, dead_zone: f32) -> f32 {
+        let (along, across) = axis.stick();
+        let (x, y) = (self.read(along), self.read(across));
+
+        let magnitude = x.hypot(y);
+        if magnitude <= dead_zone {
+            return 0.0;
+        }
+        let scaled = ((magnitude - dead_zone) / (1.0 - dead_zone)).min(1.0);
+        x / magnitude * scaled
+    }
+
+    fn read(&self, axis: Axis) -> f32 {
+        let value = self.axes.get(&axis).copied().unwrap_or(0);
+        (value as f32 / i16::MAX as f32).max(-1.0)
+    }
+}
+
+/// Tracks which bound inputs are held and answers queries by action name.
+///
+/// The runner feeds it every event and calls [`end_frame`](Self::end_frame)
+/// after each frame, so lessons can poll it from `tick` and `update`.
+/// Unknown action names are never pressed.
+pub struct Input {
+    bindings: Bindings,
+    state: RefCell<State>,
+}
+
+impl Input {
+    pub fn new(bindings: Bindings) -> Self {
+        Self {
+            bindings,
+            state: RefCell::default(),
+        }
+    }
+
+    pub fn bindings(&self) -> &Bindings {
+        &self.bindings
+    }
+
+    pub fn handle_event(&self, event: &Event) {
+        let mut state = self.state.borrow_mut();
+        match *event {
+            Event::KeyDown {
+                keycode: Some(keycode),
+                repeat: false,
+                ..
+            } => state.press(Binding::Key(keycode)),
+            Event::KeyUp {
+                keycode: Some(keycode),
+                ..
+            } => {
+                state.held.remove(&Binding::Key(keycode));
+            }
+            Event::MouseButtonDown { mouse_btn, .. } => state.press(Binding::Mouse(mouse_btn)),
+            Event::MouseButtonUp { mouse_btn, .. } => {
+                state.held.remove(&Binding::Mouse(mouse_btn));
+            }
+            Event::ControllerButtonDown { which, button, .. } => {
+                state.pads.entry(which).or_default().buttons.insert(button);
+                state.pressed_this_frame.insert(Binding::Button(button));
+            }
+            Event::ControllerButtonUp { which, button, .. } => {
+                state.pads.entry(which).or_default().buttons.remove(&button);
+            }
+            Event::ControllerAxisMotion {
+                which, axis, value, ..
+            } => {
+                state
+                    .pads
+                    .entry(which)
+                    .or_default()
+                    .axes
+                    .insert(axis, value);
+            }
+            //Unplugging releases whatever that pad was holding
+            Event::ControllerDeviceRemoved { which, .. } => {
+                state.pads.remove(&which);
+            }
+            _ => {}
+        }
+    }
+
+    /// Forgets which inputs went down this frame.
+    pub fn end_frame(&self) {
+        self.state.borrow_mut().pressed_this_frame.clear();
+    }
+
+    /// Releases everything, e.g. when switching lessons.
+    pub fn reset(&self) {
+        *self.state.borrow_mut() = State::default();
+    }
+
+    /// Whether any input bound to `action` is held.
+    pub fn pressed(&self, action: &str) -> bool {
+        let state = self.state.borrow();
+        self.bindings
+            .bound_to(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Button(button) => {
+                    state.pads.values().any(|pad| pad.buttons.contains(button))
+                }
+                _ => state.held.contains(binding),
+            })
+    }
+
+    /// Whether any input bound to `action` went down this frame.
+    pub fn just_pressed(&self, action: &str) -> bool {
+        let state = self.state.borrow();
+        self.bindings
+            .bound_to(action)
+            .iter()
+            .any(|binding| state.pressed_this_frame.contains(binding))
+    }
+
+    /// Value of the named axis in `-1.0..=1.0`, or 0.0 if there is no such axis.
+    /// Its actions and the most deflected bound stick add up, so a d-pad and a
+    /// stick can be used together.
+    pub fn axis(&self, axis: &str) -> f32 {
+        let Some(binding) = self.bindings.axes.get(axis) else {
+            return 0.0;
+        };
+        let value = |action: &str| if self.pressed(action) { 1.0 } else { 0.0 };
+        let digital = value(&binding.positive) - value(&binding.negative);
+
+        let analog = binding.stick.map_or(0.0, |stick| {
+            let state = self.state.borrow();
+            state
+                .pads
+                .values()
+                .map(|pad| pad.stick(stick, self.bindings.dead_zone))
+                .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+                .unwrap_or(0.0)
+        });
+        (digital + analog).clamp(-1.0, 1.0)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::assets::AssetCache;
+use crate::error::Result;
+use crate::input::Input;
+use sdl2::event::Event;
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::ttf::Sdl2TtfContext;
+use sdl2::video::WindowContext;
+use sdl2::{TimerSubsystem, VideoSubsystem};
+use std::hash::Hasher;
+use std::time::Duration;
+
+/// SDL state shared with every lesson for as long as it runs.
+pub struct Context<'a> {
+    pub texture_creator: &'a TextureCreator<WindowContext>,
+    pub ttf_context: &'a Sdl2TtfContext,
+    pub input: &'a Input,
+    pub assets: AssetCache<'a>,
+    pub video: VideoSubsystem,
+    pub timer: TimerSubsystem,
+}
+
+/// One tutorial scene. Each frame the runner hands every pending event to
+/// `handle_event`, runs `tick` as many times as the fixed timestep calls for,
+/// then calls `update` and `render` once.
+pub trait Lesson<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn handle_event(&mut self, _ctx: &Context<'a>, _event: &Event) -> Result<()> {
+        Ok(())
+    }
+
+    /// Advances the simulation by one fixed step of `dt`.
+    fn tick(&mut self, _ctx: &Context<'a>, _dt: Duration) -> Result<()> {
+        Ok(())
+    }
+
+    /// Runs once per rendered frame, after any ticks.
+    fn update(&mut self, _ctx: &Context<'a>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Draws the frame. `alpha` is how far the frame falls between the last
+    /// tick and the next, for interpolating moving objects.
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()>;
+
+    /// Feeds the simulation state into `state`, so replays can tell when they
+    /// stop matching their recording.
+    fn hash_state(&self, _state: &mut dyn Hasher) {}
+}
+
+pub type LessonFactory = for<'a> fn(&Context<'a>) -> Result<Box<dyn Lesson<'a> + 'a>>;
+
+pub struct LessonInfo {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub create: LessonFactory,
+}
@@ -0,0 +1,150 @@
+use crate::clock::SdlClock;
+use crate::error::Result;
+use crate::font::load_font;
+use crate::glyph_atlas::GlyphAtlas;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use crate::timer::LTimer;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+use std::fmt::Write;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "advanced_timer",
+    title: "Advanced Timers",
+    create: |ctx| Ok(Box::new(AdvancedTimer::init(ctx)?)),
+};
+
+pub struct AdvancedTimer<'a> {
+    start_prompt_texture: LTexture<'a>,
+    pause_prompt_texture: LTexture<'a>,
+    speed_prompt_texture: LTexture<'a>,
+    time_text_prompt_texture: LTexture<'a>,
+    //The seconds are drawn from cached glyphs, reusing their string
+    numbers: GlyphAtlas<'a>,
+    time_text: String,
+    l_timer: LTimer,
+}
+
+impl<'a> Lesson<'a> for AdvancedTimer<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = load_font(ctx.ttf_context, "resources/gnd.ttf", 32)?;
+        let keys = |action| ctx.input.bindings().key_names(action);
+
+        let start_prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            &format!("Press {} to start or stop the timer", keys("start_timer")),
+            Color::RGB(0, 0, 255),
+        )?;
+        let pause_prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            &format!(
+                "Press {} to pause or unpause the timer",
+                keys("pause_timer")
+            ),
+            Color::RGB(0, 0, 255),
+        )?;
+        let speed_prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            &format!(
+                "Press {}, {} or {} to set the speed",
+                keys("speed_half"),
+                keys("speed_normal"),
+                keys("speed_double")
+            ),
+            Color::RGB(0, 0, 255),
+        )?;
+        let time_text_prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            "Seconds since start time:",
+            Color::RGB(0, 0, 0),
+        )?;
+        let mut numbers = GlyphAtlas::new(ctx.texture_creator, font)?;
+        numbers.prepare("0123456789")?;
+        numbers.set_color(0, 0, 0);
+
+        Ok(Self {
+            start_prompt_texture,
+            pause_prompt_texture,
+            speed_prompt_texture,
+            time_text_prompt_texture,
+            numbers,
+            time_text: String::from("0"),
+            l_timer: LTimer::new(SdlClock::new(ctx.timer.clone())),
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        let input = ctx.input;
+        if input.just_pressed("start_timer") {
+            if self.l_timer.is_started() {
+                self.l_timer.stop();
+            } else {
+                self.l_timer.start()
+            }
+        }
+        if input.just_pressed("pause_timer") {
+            if self.l_timer.is_paused() {
+                self.l_timer.unpause();
+            } else {
+                self.l_timer.pause()
+            }
+        }
+        for (action, time_scale) in [
+            ("speed_half", 0.5),
+            ("speed_normal", 1.0),
+            ("speed_double", 2.0),
+        ] {
+            if input.just_pressed(action) {
+                self.l_timer.set_time_scale(time_scale);
+            }
+        }
+
+        self.time_text.clear();
+        //Writing to a String can't fail
+        let _ = write!(self.time_text, "{}", self.l_timer.get_ticks().as_secs());
+        self.numbers.prepare(&self.time_text)
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+        self.start_prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.start_prompt_texture.width()) / 2) as i32,
+            0,
+            None,
+        )?;
+        self.pause_prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.pause_prompt_texture.width()) / 2) as i32,
+            self.start_prompt_texture.height() as i32,
+            None,
+        )?;
+        self.speed_prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.speed_prompt_texture.width()) / 2) as i32,
+            (self.start_prompt_texture.height() + self.pause_prompt_texture.height()) as i32,
+            None,
+        )?;
+        let time_text_texture_y = (SCREEN_HEIGHT - self.time_text_prompt_texture.height()) / 2;
+        self.time_text_prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.time_text_prompt_texture.width()) / 2) as i32,
+            (time_text_texture_y) as i32,
+            None,
+        )?;
+        let (time_width, _) = self.numbers.measure(&self.time_text);
+        self.numbers.render(
+            canvas,
+            ((SCREEN_WIDTH - time_width) / 2) as i32,
+            (time_text_texture_y + self.time_text_prompt_texture.height()) as i32,
+            &self.time_text,
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "alpha_blending",
+    title: "Alpha Blending",
+    create: |ctx| Ok(Box::new(AlphaBlending::init(ctx)?)),
+};
+
+pub struct AlphaBlending<'a> {
+    modulated_texture: LTexture<'a>,
+    background_texture: LTexture<'a>,
+    alpha: u8,
+}
+
+impl<'a> Lesson<'a> for AlphaBlending<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            modulated_texture: LTexture::load_from_file(
+                ctx.texture_creator,
+                "resources/fadeout.png",
+            )?,
+            background_texture: LTexture::load_from_file(
+                ctx.texture_creator,
+                "resources/fadein.png",
+            )?,
+            alpha: 255,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        if ctx.input.just_pressed("fade_in") {
+            self.alpha = self.alpha.saturating_add(32);
+        }
+        if ctx.input.just_pressed("fade_out") {
+            self.alpha = self.alpha.saturating_sub(32);
+        }
+        self.modulated_texture.set_alpha(self.alpha);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.background_texture.render(canvas, 0, 0, None)?;
+        self.modulated_texture.render(canvas, 0, 0, None)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::animation::AnimatedSprite;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::sheet::Sheet;
+use crate::texture::LTexture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "animated_sprites",
+    title: "Animated Sprites",
+    create: |ctx| Ok(Box::new(AnimatedSprites::init(ctx)?)),
+};
+
+pub struct AnimatedSprites<'a> {
+    sprite_texture: LTexture<'a>,
+    //One sprite per clip of the sheet: looping, ping-ponging and played once
+    sprites: Vec<AnimatedSprite>,
+}
+
+impl<'a> Lesson<'a> for AnimatedSprites<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let sheet = Sheet::load("resources/foo2.json")?;
+        let tags = ["walk", "sway", "wave"];
+
+        //Every frame is the same size, so the sprites stay evenly spaced
+        let clip = sheet.animation(tags[0])?.current_clip();
+        let spacing = SCREEN_WIDTH / (tags.len() as u32 + 1);
+        let y = ((SCREEN_HEIGHT - clip.height()) / 2) as i32;
+        let sprites = tags
+            .iter()
+            .zip(1..)
+            .map(|(tag, column)| {
+                let x = (spacing * column - clip.width() / 2) as i32;
+                Ok(AnimatedSprite::new(sheet.animation(tag)?, x, y))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            sprite_texture: LTexture::load_from_file(ctx.texture_creator, sheet.image())?,
+            sprites,
+        })
+    }
+
+    fn tick(&mut self, _ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        for sprite in &mut self.sprites {
+            sprite.update(dt);
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        if ctx.input.just_pressed("replay_animation") {
+            for sprite in &mut self.sprites {
+                sprite.animation.restart();
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        for sprite in &self.sprites {
+            sprite.render(&self.sprite_texture, canvas)?;
+        }
+        Ok(())
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        for sprite in &self.sprites {
+            sprite.animation.hash_state(state);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::bitmap_font::BitmapFont;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "bitmap_fonts",
+    title: "Bitmap Fonts",
+    create: |ctx| Ok(Box::new(BitmapFonts::init(ctx)?)),
+};
+
+const TEXT: &str =
+    "Bitmap Font:\nABDCEFGHIJKLMNOPQRSTUVWXYZ\nabcdefghijklmnopqrstuvwxyz\n0123456789";
+
+pub struct BitmapFonts<'a> {
+    font: BitmapFont<'a>,
+}
+
+impl<'a> Lesson<'a> for BitmapFonts<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        //The sheet is white on black, so tint it to show up on white
+        let mut font = BitmapFont::load(ctx.texture_creator, "resources/lazyfont.png")?
+            .with_glyphs(|glyphs| glyphs.with_spacing(2).with_line_spacing(6));
+        font.set_color(0, 0, 0);
+        Ok(Self { font })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.font.render(canvas, 10, 10, TEXT)
+    }
+}
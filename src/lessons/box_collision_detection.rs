@@ -0,0 +1,63 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::collision::Shape;
+use crate::dot::Dot;
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "box_collision_detection",
+    title: "Collision Detection",
+    create: |ctx| Ok(Box::new(BoxCollisionDetection::init(ctx)?)),
+};
+
+//Pixels per second
+const DOT_VEL: f32 = 240.0;
+
+pub struct BoxCollisionDetection<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    dot: Dot,
+    wall: Rect,
+}
+
+impl<'a> Lesson<'a> for BoxCollisionDetection<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            dot: Dot::new(0.0, 0.0, DOT_VEL),
+            wall: Rect::new(300, 40, 40, 400),
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        let screen = Rect::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT);
+        let wall = Shape::Aabb(self.wall);
+        self.dot
+            .move_position_with(screen, dt, &[wall], |dot| Shape::Aabb(dot.collider()));
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        //Render wall
+        canvas.set_draw_color(Color::RGB(0, 0, 255));
+        canvas.draw_rect(self.wall).map_err(Error::render)?;
+
+        self.dot.render(&dot_texture, canvas, 0, 0, alpha)
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+    }
+}
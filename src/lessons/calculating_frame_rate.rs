@@ -0,0 +1,85 @@
+use crate::clock::SdlClock;
+use crate::error::Result;
+use crate::font::load_font;
+use crate::fps::FpsCounter;
+use crate::glyph_atlas::GlyphAtlas;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+use std::fmt::Write;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "calculating_frame_rate",
+    title: "Calculating Frame Rate",
+    create: |ctx| Ok(Box::new(CalculatingFrameRate::init(ctx)?)),
+};
+
+const TEXT_COLOR: Color = Color::RGB(0, 0, 0);
+
+pub struct CalculatingFrameRate<'a> {
+    time_text_prompt_texture: LTexture<'a>,
+    //The average is drawn from cached glyphs, reusing its string
+    numbers: GlyphAtlas<'a>,
+    time_text: String,
+    //The frames per second counter
+    fps: FpsCounter,
+}
+
+impl<'a> Lesson<'a> for CalculatingFrameRate<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = load_font(ctx.ttf_context, "resources/lazy.ttf", 32)?;
+
+        let time_text_prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            "Average Frames Per Second:",
+            TEXT_COLOR,
+        )?;
+        let mut numbers = GlyphAtlas::new(ctx.texture_creator, font)?;
+        numbers.prepare("0123456789.")?;
+        numbers.set_color(TEXT_COLOR.r, TEXT_COLOR.g, TEXT_COLOR.b);
+
+        //Start counting frames per second
+        let fps = FpsCounter::new(SdlClock::new(ctx.timer.clone()));
+
+        Ok(Self {
+            time_text_prompt_texture,
+            numbers,
+            time_text: String::from("0.00"),
+            fps,
+        })
+    }
+
+    fn update(&mut self, _ctx: &Context<'a>) -> Result<()> {
+        //Set text to be rendered
+        self.time_text.clear();
+        //Writing to a String can't fail
+        let _ = write!(self.time_text, "{:.2}", self.fps.average());
+        self.numbers.prepare(&self.time_text)?;
+
+        self.fps.count_frame();
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        let time_text_texture_y = (SCREEN_HEIGHT - self.time_text_prompt_texture.height()) / 2;
+        self.time_text_prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.time_text_prompt_texture.width()) / 2) as i32,
+            (time_text_texture_y) as i32,
+            None,
+        )?;
+        let (time_width, time_height) = self.numbers.measure(&self.time_text);
+        self.numbers.render(
+            canvas,
+            ((SCREEN_WIDTH - time_width) / 2) as i32,
+            (time_text_texture_y + time_height) as i32,
+            &self.time_text,
+        )
+    }
+}
@@ -0,0 +1,87 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::collision::{Circle, Shape};
+use crate::dot::Dot;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::render_queue::{Depth, RenderQueue};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "circular_collision_detection",
+    title: "Circular Collision Detection",
+    create: |ctx| Ok(Box::new(CircularCollisionDetection::init(ctx)?)),
+};
+
+//The dot's circular collider, centered on the dot
+fn dot_collider(dot: &Dot) -> Shape {
+    let r = Dot::DOT_WIDTH as i32 / 2;
+    let position = dot.collider();
+    Shape::Circle(Circle::new(position.x() + r, position.y() + r, r))
+}
+
+//Pixels per second
+const DOT_VEL: f32 = 240.0;
+
+//The wall is drawn behind the dots, which are sorted by how far down they are
+const WALL_LAYER: i32 = 0;
+const DOT_LAYER: i32 = 1;
+
+pub struct CircularCollisionDetection<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    dot: Dot,
+    other_dot: Dot,
+    other_collider: Shape,
+    wall: Rect,
+}
+
+impl<'a> Lesson<'a> for CircularCollisionDetection<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let other_dot = Dot::new(
+            (SCREEN_WIDTH / 4 - Dot::DOT_WIDTH / 2) as f32,
+            (SCREEN_HEIGHT / 4 - Dot::DOT_HEIGHT / 2) as f32,
+            DOT_VEL,
+        );
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            dot: Dot::new(0.0, 0.0, DOT_VEL),
+            other_collider: dot_collider(&other_dot),
+            other_dot,
+            wall: Rect::new(300, 40, 40, 400),
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        let screen = Rect::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT);
+        let obstacles = [Shape::Aabb(self.wall), self.other_collider.clone()];
+        self.dot
+            .move_position_with(screen, dt, &obstacles, dot_collider);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        let mut queue = RenderQueue::new();
+        queue.draw_rect(Depth::layer(WALL_LAYER), self.wall, Color::RGB(0, 0, 255));
+        self.other_dot
+            .queue(&mut queue, &dot_texture, DOT_LAYER, 0, 0, alpha);
+        self.dot
+            .queue(&mut queue, &dot_texture, DOT_LAYER, 0, 0, alpha);
+        queue.flush(canvas)?;
+        Ok(())
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+    }
+}
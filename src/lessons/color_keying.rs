@@ -0,0 +1,32 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "color_keying",
+    title: "Color Keying",
+    create: |ctx| Ok(Box::new(ColorKeying::init(ctx)?)),
+};
+
+pub struct ColorKeying<'a> {
+    background_texture: LTexture<'a>,
+    foo_texture: LTexture<'a>,
+}
+
+impl<'a> Lesson<'a> for ColorKeying<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            background_texture: LTexture::load_from_file(
+                ctx.texture_creator,
+                "resources/background.png",
+            )?,
+            foo_texture: LTexture::load_from_file(ctx.texture_creator, "resources/foo.png")?,
+        })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        self.background_texture.render(canvas, 0, 0, None)?;
+        self.foo_texture.render(canvas, 240, 190, None)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "color_modulation",
+    title: "Color Modulation",
+    create: |ctx| Ok(Box::new(ColorModulation::init(ctx)?)),
+};
+
+pub struct ColorModulation<'a> {
+    texture: LTexture<'a>,
+    red_tint: u8,
+    green_tint: u8,
+    blue_tint: u8,
+}
+
+impl<'a> Lesson<'a> for ColorModulation<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            texture: LTexture::load_from_file_with_color_key(
+                ctx.texture_creator,
+                "resources/colors.png",
+                None,
+            )?,
+            red_tint: 255,
+            green_tint: 255,
+            blue_tint: 255,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        let input = ctx.input;
+        if input.just_pressed("tint_red_up") && self.red_tint < 224 {
+            self.red_tint += 32;
+        }
+        if input.just_pressed("tint_green_up") && self.green_tint < 224 {
+            self.green_tint += 32;
+        }
+        if input.just_pressed("tint_blue_up") && self.blue_tint < 224 {
+            self.blue_tint += 32;
+        }
+        if input.just_pressed("tint_red_down") && self.red_tint > 32 {
+            self.red_tint -= 32;
+        }
+        if input.just_pressed("tint_green_down") && self.green_tint > 32 {
+            self.green_tint -= 32;
+        }
+        if input.just_pressed("tint_blue_down") && self.blue_tint > 32 {
+            self.blue_tint -= 32;
+        }
+        self.texture
+            .set_color(self.red_tint, self.green_tint, self.blue_tint);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+        self.texture.render(canvas, 0, 0, None)
+    }
+}
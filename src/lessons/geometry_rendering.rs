@@ -0,0 +1,62 @@
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "geometry_rendering",
+    title: "Geometry Rendering",
+    create: |ctx| Ok(Box::new(GeometryRendering::init(ctx)?)),
+};
+
+pub struct GeometryRendering;
+
+impl<'a> Lesson<'a> for GeometryRendering {
+    fn init(_ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self)
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        canvas.set_draw_color(Color::RGB(255, 0, 0));
+        canvas
+            .fill_rect(Rect::new(
+                (SCREEN_WIDTH / 4) as i32,
+                (SCREEN_HEIGHT / 4) as i32,
+                SCREEN_WIDTH / 2,
+                SCREEN_HEIGHT / 2,
+            ))
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(255, 0, 255));
+        canvas
+            .draw_rect(Rect::new(
+                (SCREEN_WIDTH / 6) as i32,
+                (SCREEN_HEIGHT / 6) as i32,
+                SCREEN_WIDTH * 2 / 3,
+                SCREEN_HEIGHT * 2 / 3,
+            ))
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(0, 0, 255));
+        canvas
+            .draw_line(
+                Point::new(0, (SCREEN_HEIGHT / 2) as i32),
+                Point::new(SCREEN_WIDTH as i32, (SCREEN_HEIGHT / 2) as i32),
+            )
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        for i in (0..SCREEN_HEIGHT).step_by(4) {
+            canvas
+                .draw_point(Point::new((SCREEN_WIDTH / 2) as i32, i as i32))
+                .map_err(Error::render)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::render::WindowCanvas;
+use std::collections::HashMap;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "key_presses",
+    title: "Key Presses",
+    create: |ctx| Ok(Box::new(KeyPresses::init(ctx)?)),
+};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+enum KeyPress {
+    Up,
+    Down,
+    Left,
+    Right,
+    Press,
+}
+
+pub struct KeyPresses<'a> {
+    assets: AssetCache<'a>,
+    textures: HashMap<KeyPress, TextureHandle>,
+    current: KeyPress,
+}
+
+impl<'a> Lesson<'a> for KeyPresses<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let mut textures = HashMap::new();
+        for (key, path) in [
+            (KeyPress::Up, "resources/up.bmp"),
+            (KeyPress::Down, "resources/down.bmp"),
+            (KeyPress::Left, "resources/left.bmp"),
+            (KeyPress::Right, "resources/right.bmp"),
+            (KeyPress::Press, "resources/press.bmp"),
+        ] {
+            let texture = ctx.assets.load_texture_with_color_key(path, None)?;
+            textures.insert(key, texture);
+        }
+
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            textures,
+            current: KeyPress::Press,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        for (action, key) in [
+            ("move_up", KeyPress::Up),
+            ("move_down", KeyPress::Down),
+            ("move_left", KeyPress::Left),
+            ("move_right", KeyPress::Right),
+        ] {
+            if ctx.input.just_pressed(action) {
+                self.current = key;
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        let texture = self.assets.texture(&self.textures[&self.current])?;
+        texture.render(canvas, 0, 0, None)
+    }
+}
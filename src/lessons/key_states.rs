@@ -0,0 +1,53 @@
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::load_texture;
+use sdl2::render::{Texture, WindowCanvas};
+use std::collections::HashMap;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "key_states",
+    title: "Key States",
+    create: |ctx| Ok(Box::new(KeyStates::init(ctx)?)),
+};
+
+pub struct KeyStates<'a> {
+    sprites: HashMap<&'static str, Texture<'a>>,
+    current_image: &'static str,
+}
+
+impl<'a> Lesson<'a> for KeyStates<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let mut sprites = HashMap::new();
+        for name in ["up", "down", "left", "right", "press"] {
+            let path = format!("resources/{name}.bmp");
+            sprites.insert(name, load_texture(ctx.texture_creator, path)?);
+        }
+
+        Ok(Self {
+            sprites,
+            current_image: "press",
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        for (action, image) in [
+            ("move_up", "up"),
+            ("move_down", "down"),
+            ("move_right", "right"),
+            ("move_left", "left"),
+        ] {
+            if ctx.input.pressed(action) {
+                self.current_image = image;
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        // Clear and render the currently selected image
+        canvas.clear();
+        canvas
+            .copy(&self.sprites[self.current_image], None, None)
+            .map_err(Error::render)
+    }
+}
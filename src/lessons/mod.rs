@@ -0,0 +1,72 @@
+pub mod advanced_timer;
+pub mod alpha_blending;
+pub mod animated_sprites;
+pub mod bitmap_fonts;
+pub mod box_collision_detection;
+pub mod calculating_frame_rate;
+pub mod capping_frame_rate;
+pub mod circular_collision_detection;
+pub mod color_keying;
+pub mod color_modulation;
+pub mod geometry_rendering;
+pub mod key_presses;
+pub mod key_states;
+pub mod motion;
+pub mod mouse_events;
+pub mod nine_slice;
+pub mod open_window;
+pub mod per_pixel_collision_detection;
+pub mod render_to_texture;
+pub mod rotation_and_flipping;
+pub mod scrolling;
+pub mod scrolling_backgrounds;
+pub mod sound_effects;
+pub mod sprite_sheet;
+pub mod surfaces;
+pub mod text_input_and_clipboard;
+pub mod texture;
+pub mod texture_manipulation;
+pub mod timer;
+pub mod ttf_fonts;
+pub mod viewport;
+
+use crate::lesson::LessonInfo;
+
+//Every lesson, in tutorial order
+pub const ALL: &[&LessonInfo] = &[
+    &open_window::LESSON,
+    &surfaces::LESSON,
+    &texture::LESSON,
+    &key_presses::LESSON,
+    &geometry_rendering::LESSON,
+    &viewport::LESSON,
+    &color_keying::LESSON,
+    &sprite_sheet::LESSON,
+    &color_modulation::LESSON,
+    &alpha_blending::LESSON,
+    &animated_sprites::LESSON,
+    &rotation_and_flipping::LESSON,
+    &ttf_fonts::LESSON,
+    &mouse_events::LESSON,
+    &key_states::LESSON,
+    &sound_effects::LESSON,
+    &timer::LESSON,
+    &advanced_timer::LESSON,
+    &calculating_frame_rate::LESSON,
+    &capping_frame_rate::LESSON,
+    &motion::LESSON,
+    &box_collision_detection::LESSON,
+    &per_pixel_collision_detection::LESSON,
+    &circular_collision_detection::LESSON,
+    &scrolling::LESSON,
+    &scrolling_backgrounds::LESSON,
+    &text_input_and_clipboard::LESSON,
+    &texture_manipulation::LESSON,
+    &bitmap_fonts::LESSON,
+    &render_to_texture::LESSON,
+    &nine_slice::LESSON,
+];
+
+pub fn by_name(name: &str) -> Option<&'static LessonInfo> {
+    ALL.iter().copied().find(|info| info.name == name)
+}
@@ -0,0 +1,54 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::dot::Dot;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "motion",
+    title: "Motion",
+    create: |ctx| Ok(Box::new(Motion::init(ctx)?)),
+};
+
+//Pixels per second
+const DOT_VEL: f32 = 600.0;
+
+pub struct Motion<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    dot: Dot,
+}
+
+impl<'a> Lesson<'a> for Motion<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            dot: Dot::new(0.0, 0.0, DOT_VEL),
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        self.dot
+            .move_position(Rect::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT), dt);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.dot.render(&dot_texture, canvas, 0, 0, alpha)
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+    }
+}
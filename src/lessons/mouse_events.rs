@@ -0,0 +1,117 @@
+use crate::atlas::{Atlas, AtlasBuilder, Region};
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::event::Event;
+use sdl2::pixels::Color;
+use sdl2::rect::Point;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "mouse_events",
+    title: "Mouse Events",
+    create: |ctx| Ok(Box::new(MouseEvents::init(ctx)?)),
+};
+
+const TOTAL_BUTTONS: usize = 4;
+const BUTTON_WIDTH: u32 = 300;
+const BUTTON_HEIGHT: u32 = 200;
+
+#[derive(Copy, Clone)]
+enum LButtonSprite {
+    Out = 0,
+    OverMotion,
+    Down,
+    Up,
+}
+
+struct LButton {
+    position: Point,
+    current_sprite: LButtonSprite,
+}
+
+impl LButton {
+    fn new(position: Point) -> LButton {
+        LButton {
+            position,
+            current_sprite: LButtonSprite::Out,
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) {
+        let (x, y, sprite) = match *event {
+            Event::MouseMotion { x, y, .. } => (x, y, LButtonSprite::OverMotion),
+            Event::MouseButtonDown { x, y, .. } => (x, y, LButtonSprite::Down),
+            Event::MouseButtonUp { x, y, .. } => (x, y, LButtonSprite::Up),
+            _ => return,
+        };
+
+        if (x < self.position.x())
+            || (x > self.position.x() + BUTTON_WIDTH as i32)
+            || (y < self.position.y())
+            || (y > self.position.y() + BUTTON_HEIGHT as i32)
+        {
+            self.current_sprite = LButtonSprite::Out;
+        } else {
+            self.current_sprite = sprite;
+        }
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, atlas: &Atlas, clips: &[Region]) -> Result<()> {
+        let indx = self.current_sprite as usize;
+        atlas.render(canvas, clips[indx], self.position.x(), self.position.y())
+    }
+}
+
+pub struct MouseEvents<'a> {
+    atlas: Atlas<'a>,
+    clip_rects: Vec<Region>,
+    buttons: [LButton; TOTAL_BUTTONS],
+}
+
+impl<'a> Lesson<'a> for MouseEvents<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let atlas = AtlasBuilder::new()
+            .add_sheet(
+                "button",
+                "resources/button.png",
+                BUTTON_WIDTH,
+                BUTTON_HEIGHT,
+            )
+            .build(ctx.texture_creator)?;
+        let clip_rects = atlas.tiles("button")?.to_vec();
+
+        let buttons = [
+            LButton::new(Point::new(0, 0)),
+            LButton::new(Point::new((SCREEN_WIDTH - BUTTON_WIDTH) as i32, 0)),
+            LButton::new(Point::new(0, (SCREEN_HEIGHT - BUTTON_HEIGHT) as i32)),
+            LButton::new(Point::new(
+                (SCREEN_WIDTH - BUTTON_WIDTH) as i32,
+                (SCREEN_HEIGHT - BUTTON_HEIGHT) as i32,
+            )),
+        ];
+
+        Ok(Self {
+            atlas,
+            clip_rects,
+            buttons,
+        })
+    }
+
+    fn handle_event(&mut self, _ctx: &Context<'a>, event: &Event) -> Result<()> {
+        for button in &mut self.buttons {
+            button.handle_event(event);
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(0xff, 0xff, 0xff));
+        canvas.clear();
+
+        for button in &self.buttons {
+            button.render(canvas, &self.atlas, &self.clip_rects)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use crate::bitmap_font::BitmapFont;
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::nine_slice::{Fill, Insets, NineSlice};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::event::Event;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "nine_slice",
+    title: "Nine-Slice Panels",
+    create: |ctx| Ok(Box::new(NineSlicePanels::init(ctx)?)),
+};
+
+const MESSAGE: &str = "One small image makes\npanels and buttons\nof any size.";
+const LABELS: [&str; 3] = ["OK", "Cancel", "Apply to all"];
+
+//ui.png holds a panel, a button and a pressed button side by side
+const CELL_SIZE: u32 = 32;
+const PANEL_CELL: i32 = 0;
+const BUTTON_CELL: i32 = 1;
+const PRESSED_CELL: i32 = 2;
+
+//Room between the borders and what they hold
+const PANEL_PADDING: u32 = 12;
+const BUTTON_PADDING: u32 = 8;
+const GAP: u32 = 16;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ButtonState {
+    Out,
+    Over,
+    Down,
+}
+
+fn cell(index: i32) -> Rect {
+    Rect::new(index * CELL_SIZE as i32, 0, CELL_SIZE, CELL_SIZE)
+}
+
+struct Button {
+    label: &'static str,
+    rect: Rect,
+    state: ButtonState,
+}
+
+impl Button {
+    fn handle_event(&mut self, event: &Event) {
+        match *event {
+            Event::MouseMotion { x, y, .. } => {
+                if !self.rect.contains_point((x, y)) {
+                    self.state = ButtonState::Out;
+                } else if self.state == ButtonState::Out {
+                    self.state = ButtonState::Over;
+                }
+            }
+            Event::MouseButtonDown { x, y, .. } if self.rect.contains_point((x, y)) => {
+                self.state = ButtonState::Down;
+            }
+            Event::MouseButtonUp { x, y, .. } if self.rect.contains_point((x, y)) => {
+                self.state = ButtonState::Over;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub struct NineSlicePanels<'a> {
+    panel: NineSlice<'a>,
+    button: NineSlice<'a>,
+    font: BitmapFont<'a>,
+    panel_rect: Rect,
+    buttons: Vec<Button>,
+}
+
+impl<'a> Lesson<'a> for NineSlicePanels<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        //The panel's checkered center repeats, the bevelled button stretches
+        let panel = NineSlice::load(ctx.texture_creator, "resources/ui.png", Insets::uniform(8))?
+            .with_slices(|slices| slices.with_center(Fill::Tile));
+        let button = NineSlice::load(ctx.texture_creator, "resources/ui.png", Insets::uniform(4))?;
+        let mut font = BitmapFont::load(ctx.texture_creator, "resources/lazyfont.png")?
+            .with_glyphs(|glyphs| glyphs.with_spacing(2).with_line_spacing(6));
+        font.set_color(0, 0, 0);
+
+        //Size every button to its label, then the panel to the message and buttons
+        let sizes: Vec<_> = LABELS
+            .iter()
+            .map(|label| {
+                let (width, height) = font.glyphs().measure(label);
+                button
+                    .slices()
+                    .fit(width + BUTTON_PADDING * 2, height + BUTTON_PADDING)
+            })
+            .collect();
+        let row_width = sizes.iter().map(|(w, _)| w).sum::<u32>() + GAP * (LABELS.len() as u32 - 1);
+        let row_height = sizes.iter().map(|&(_, h)| h).max().unwrap_or(0);
+        let (message_width, message_height) = font.glyphs().measure(MESSAGE);
+
+        let (panel_width, panel_height) = panel.slices().fit(
+            message_width.max(row_width) + PANEL_PADDING * 2,
+            message_height + GAP + row_height + PANEL_PADDING * 2,
+        );
+        let panel_rect = Rect::from_center(
+            ((SCREEN_WIDTH / 2) as i32, (SCREEN_HEIGHT / 2) as i32),
+            panel_width,
+            panel_height,
+        );
+
+        let content = panel.slices().insets();
+        let mut x = panel_rect.right() - (content.right + PANEL_PADDING + row_width) as i32;
+        let y = panel_rect.bottom() - (content.bottom + PANEL_PADDING + row_height) as i32;
+        let buttons = LABELS
+            .iter()
+            .zip(sizes)
+            .map(|(&label, (width, height))| {
+                let rect = Rect::new(x, y, width, height);
+                x += (width + GAP) as i32;
+                Button {
+                    label,
+                    rect,
+                    state: ButtonState::Out,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            panel,
+            button,
+            font,
+            panel_rect,
+            buttons,
+        })
+    }
+
+    fn handle_event(&mut self, _ctx: &Context<'a>, event: &Event) -> Result<()> {
+        for button in &mut self.buttons {
+            button.handle_event(event);
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(0x60, 0x70, 0x80));
+        canvas.clear();
+
+        self.panel
+            .render(canvas, Some(cell(PANEL_CELL)), self.panel_rect)?;
+        let insets = self.panel.slices().insets();
+        self.font.render(
+            canvas,
+            self.panel_rect.x() + (insets.left + PANEL_PADDING) as i32,
+            self.panel_rect.y() + (insets.top + PANEL_PADDING) as i32,
+            MESSAGE,
+        )?;
+
+        for button in &self.buttons {
+            let (clip, offset) = match button.state {
+                ButtonState::Down => (PRESSED_CELL, 1),
+                _ => (BUTTON_CELL, 0),
+            };
+            self.button.render(canvas, Some(cell(clip)), button.rect)?;
+
+            let (width, height) = self.font.glyphs().measure(button.label);
+            let label = Rect::from_center(button.rect.center(), width, height);
+            self.font
+                .render(canvas, label.x() + offset, label.y() + offset, button.label)?;
+
+            if button.state == ButtonState::Over {
+                canvas.set_draw_color(Color::RGB(0xff, 0xc0, 0x20));
+                canvas.draw_rect(button.rect).map_err(Error::render)?;
+            }
+        }
+        Ok(())
+    }
+}
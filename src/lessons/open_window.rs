@@ -0,0 +1,24 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "open_window",
+    title: "Open Window",
+    create: |ctx| Ok(Box::new(OpenWindow::init(ctx)?)),
+};
+
+pub struct OpenWindow;
+
+impl<'a> Lesson<'a> for OpenWindow {
+    fn init(_ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self)
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        canvas.clear();
+        Ok(())
+    }
+}
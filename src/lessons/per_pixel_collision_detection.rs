@@ -0,0 +1,109 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::collision::Shape;
+use crate::dot::Dot;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::slice;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "per_pixel_collision_detection",
+    title: "Per-pixel Collision Detection",
+    create: |ctx| Ok(Box::new(PerPixelCollisionDetection::init(ctx)?)),
+};
+
+//Width and height of each of the dot's collision boxes, from top to bottom
+const DOT_COLLIDER_SIZES: [(u32, u32); 11] = [
+    (6, 1),
+    (10, 1),
+    (14, 1),
+    (16, 2),
+    (18, 2),
+    (20, 6),
+    (18, 2),
+    (16, 2),
+    (14, 1),
+    (10, 1),
+    (6, 1),
+];
+
+//Pixels per second
+const DOT_VEL: f32 = 240.0;
+
+pub struct PerPixelCollisionDetection<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    dot: Dot,
+    other_dot: Dot,
+    other_colliders: Shape,
+}
+
+impl<'a> Lesson<'a> for PerPixelCollisionDetection<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let other_dot = Dot::new(
+            (SCREEN_WIDTH / 4) as f32,
+            (SCREEN_HEIGHT / 4) as f32,
+            DOT_VEL,
+        );
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            dot: Dot::new(0.0, 0.0, DOT_VEL),
+            other_colliders: dot_colliders(&other_dot),
+            other_dot,
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        let screen = Rect::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT);
+        let obstacles = slice::from_ref(&self.other_colliders);
+        self.dot
+            .move_position_with(screen, dt, obstacles, dot_colliders);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.dot.render(&dot_texture, canvas, 0, 0, alpha)?;
+        self.other_dot.render(&dot_texture, canvas, 0, 0, alpha)
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+    }
+}
+
+fn dot_colliders(dot: &Dot) -> Shape {
+    let position = dot.collider();
+
+    //The row offset
+    let mut r = 0;
+
+    //Go through the dot's collision boxes
+    let boxes = DOT_COLLIDER_SIZES
+        .iter()
+        .map(|&(w, h)| {
+            //Center the collision box and set it at its row offset
+            let collider = Rect::new(
+                position.x() + (Dot::DOT_WIDTH - w) as i32 / 2,
+                position.y() + r,
+                w,
+                h,
+            );
+
+            //Move the row offset down the height of the collision box
+            r += h as i32;
+            collider
+        })
+        .collect();
+    Shape::Boxes(boxes)
+}
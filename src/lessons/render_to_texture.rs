@@ -0,0 +1,145 @@
+use crate::error::{Error, Result};
+use crate::font::load_font;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::{LTexture, Transform};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::{TextureAccess, WindowCanvas};
+use std::cell::RefCell;
+use std::f64::consts::TAU;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "render_to_texture",
+    title: "Render to Texture",
+    create: |ctx| Ok(Box::new(RenderToTexture::init(ctx)?)),
+};
+
+const DEGREES_PER_SECOND: f64 = 120.0;
+//How long the scene takes to shrink to its smallest and grow back
+const PULSE_SECONDS: f64 = 4.0;
+
+pub struct RenderToTexture<'a> {
+    //Drawing into the target needs it mutably, but rendering only gets &self
+    target: RefCell<LTexture<'a>>,
+    arrow: LTexture<'a>,
+    text: LTexture<'a>,
+    degrees: f64,
+    pulse: f64,
+}
+
+impl<'a> RenderToTexture<'a> {
+    //The geometry_rendering scene, with a sprite and a caption on top
+    fn draw_scene(&self, canvas: &mut WindowCanvas) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        canvas.set_draw_color(Color::RGB(255, 0, 0));
+        canvas
+            .fill_rect(Rect::new(
+                (SCREEN_WIDTH / 4) as i32,
+                (SCREEN_HEIGHT / 4) as i32,
+                SCREEN_WIDTH / 2,
+                SCREEN_HEIGHT / 2,
+            ))
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(0, 255, 0));
+        canvas
+            .draw_rect(Rect::new(
+                (SCREEN_WIDTH / 6) as i32,
+                (SCREEN_HEIGHT / 6) as i32,
+                SCREEN_WIDTH * 2 / 3,
+                SCREEN_HEIGHT * 2 / 3,
+            ))
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(0, 0, 255));
+        canvas
+            .draw_line(
+                Point::new(0, (SCREEN_HEIGHT / 2) as i32),
+                Point::new(SCREEN_WIDTH as i32, (SCREEN_HEIGHT / 2) as i32),
+            )
+            .map_err(Error::render)?;
+
+        canvas.set_draw_color(Color::RGB(255, 255, 0));
+        for i in (0..SCREEN_HEIGHT).step_by(4) {
+            canvas
+                .draw_point(Point::new((SCREEN_WIDTH / 2) as i32, i as i32))
+                .map_err(Error::render)?;
+        }
+
+        self.arrow.render(
+            canvas,
+            (SCREEN_WIDTH - self.arrow.width()) as i32 / 2,
+            (SCREEN_HEIGHT - self.arrow.height()) as i32 / 2,
+            None,
+        )?;
+        self.text.render(
+            canvas,
+            (SCREEN_WIDTH - self.text.width()) as i32 / 2,
+            (SCREEN_HEIGHT / 6) as i32 + 8,
+            None,
+        )
+    }
+}
+
+impl<'a> Lesson<'a> for RenderToTexture<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = load_font(ctx.ttf_context, "resources/lazy.ttf", 28)?;
+        Ok(Self {
+            target: RefCell::new(LTexture::create_blank(
+                ctx.texture_creator,
+                SCREEN_WIDTH,
+                SCREEN_HEIGHT,
+                TextureAccess::Target,
+            )?),
+            arrow: LTexture::load_from_file(ctx.texture_creator, "resources/arrow.png")?,
+            text: LTexture::load_from_rendered_text(
+                ctx.texture_creator,
+                &font,
+                "Rendered to a texture",
+                Color::RGB(0, 0, 0),
+            )?,
+            degrees: 0.0,
+            pulse: 0.0,
+        })
+    }
+
+    fn tick(&mut self, _ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.degrees = (self.degrees + DEGREES_PER_SECOND * dt.as_secs_f64()) % 360.0;
+        self.pulse = (self.pulse + dt.as_secs_f64() / PULSE_SECONDS) % 1.0;
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        let mut target = self.target.borrow_mut();
+        target.with_target(canvas, |canvas| self.draw_scene(canvas))?;
+
+        canvas.set_draw_color(Color::RGB(0, 0, 0));
+        canvas.clear();
+
+        //Spin the whole scene around the middle of the screen, between half and full size
+        let scale = 0.75 + 0.25 * (self.pulse * TAU).cos();
+        let width = (target.width() as f64 * scale).round() as i32;
+        let height = (target.height() as f64 * scale).round() as i32;
+        target.render_ex(
+            canvas,
+            (SCREEN_WIDTH as i32 - width) / 2,
+            (SCREEN_HEIGHT as i32 - height) / 2,
+            None,
+            Transform {
+                angle: self.degrees,
+                scale,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        state.write_u64(self.degrees.to_bits());
+        state.write_u64(self.pulse.to_bits());
+    }
+}
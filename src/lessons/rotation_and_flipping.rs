@@ -0,0 +1,69 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::{LTexture, Transform};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "rotation_and_flipping",
+    title: "Rotation and Flipping",
+    create: |ctx| Ok(Box::new(RotationAndFlipping::init(ctx)?)),
+};
+
+pub struct RotationAndFlipping<'a> {
+    arrow: LTexture<'a>,
+    degrees: f64,
+    flip_vertical: bool,
+    flip_horizontal: bool,
+}
+
+impl<'a> Lesson<'a> for RotationAndFlipping<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            arrow: LTexture::load_from_file(ctx.texture_creator, "resources/arrow.png")?,
+            degrees: 0.0,
+            flip_vertical: false,
+            flip_horizontal: false,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        let input = ctx.input;
+        if input.just_pressed("rotate_left") {
+            self.degrees -= 60.0;
+        }
+        if input.just_pressed("rotate_right") {
+            self.degrees += 60.0;
+        }
+        if input.just_pressed("flip_horizontal") {
+            self.flip_horizontal = !self.flip_horizontal;
+        }
+        if input.just_pressed("flip_none") {
+            self.flip_horizontal = false;
+            self.flip_vertical = false;
+        }
+        if input.just_pressed("flip_vertical") {
+            self.flip_vertical = !self.flip_vertical;
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.arrow.render_ex(
+            canvas,
+            (SCREEN_WIDTH - self.arrow.width()) as i32 / 2,
+            (SCREEN_HEIGHT - self.arrow.height()) as i32 / 2,
+            None,
+            Transform {
+                angle: self.degrees,
+                flip_horizontal: self.flip_horizontal,
+                flip_vertical: self.flip_vertical,
+                ..Default::default()
+            },
+        )
+    }
+}
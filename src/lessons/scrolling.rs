@@ -0,0 +1,94 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::dot::Dot;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "scrolling",
+    title: "Scrolling",
+    create: |ctx| Ok(Box::new(Scrolling::init(ctx)?)),
+};
+
+const LEVEL_WIDTH: u32 = 1280;
+const LEVEL_HEIGHT: u32 = 960;
+//Pixels per second
+const DOT_VEL: f32 = 240.0;
+
+pub struct Scrolling<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    bg_texture: TextureHandle,
+    dot: Dot,
+}
+
+impl<'a> Lesson<'a> for Scrolling<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            bg_texture: ctx.assets.load_texture("resources/bg.png")?,
+            dot: Dot::new(
+                (Dot::DOT_WIDTH / 2) as f32,
+                (Dot::DOT_HEIGHT / 2) as f32,
+                DOT_VEL,
+            ),
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        self.dot
+            .move_position(Rect::new(0, 0, LEVEL_WIDTH, LEVEL_HEIGHT), dt);
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        let bg_texture = self.assets.texture(&self.bg_texture)?;
+        let camera = camera_over(&self.dot, alpha);
+
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        //Render background
+        bg_texture.render(canvas, 0, 0, Some(camera))?;
+
+        self.dot
+            .render(&dot_texture, canvas, camera.x, camera.y, alpha)
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+    }
+}
+
+//The camera centered over where the dot is drawn this frame, kept inside the level
+fn camera_over(dot: &Dot, alpha: f32) -> Rect {
+    let position = dot.position_at(alpha);
+    let mut camera = Rect::new(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    //Center the camera over the dot
+    camera.x = (position.x.round() as i32 + Dot::DOT_WIDTH as i32 / 2) - SCREEN_WIDTH as i32 / 2;
+    camera.y = (position.y.round() as i32 + Dot::DOT_HEIGHT as i32 / 2) - SCREEN_HEIGHT as i32 / 2;
+
+    //Keep the camera in bounds
+    if camera.x < 0 {
+        camera.x = 0;
+    }
+    if camera.y < 0 {
+        camera.y = 0;
+    }
+    if camera.x > LEVEL_WIDTH as i32 - camera.w {
+        camera.x = LEVEL_WIDTH as i32 - camera.w;
+    }
+    if camera.y > LEVEL_HEIGHT as i32 - camera.h {
+        camera.y = LEVEL_HEIGHT as i32 - camera.h;
+    }
+    camera
+}
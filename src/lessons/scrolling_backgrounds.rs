@@ -0,0 +1,78 @@
+use crate::assets::{AssetCache, TextureHandle};
+use crate::dot::Dot;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+use std::hash::Hasher;
+use std::time::Duration;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "scrolling_backgrounds",
+    title: "Scrolling Backgrounds",
+    create: |ctx| Ok(Box::new(ScrollingBackgrounds::init(ctx)?)),
+};
+
+const LEVEL_WIDTH: u32 = 1280;
+const LEVEL_HEIGHT: u32 = 960;
+//Pixels per second
+const DOT_VEL: f32 = 240.0;
+const SCROLL_VEL: f32 = 60.0;
+
+pub struct ScrollingBackgrounds<'a> {
+    assets: AssetCache<'a>,
+    dot_texture: TextureHandle,
+    bg_texture: TextureHandle,
+    dot: Dot,
+    scrolling_offset: f32,
+}
+
+impl<'a> Lesson<'a> for ScrollingBackgrounds<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            assets: ctx.assets.clone(),
+            dot_texture: ctx.assets.load_texture("resources/dot.bmp")?,
+            bg_texture: ctx.assets.load_texture("resources/bg_infinite.png")?,
+            dot: Dot::new(
+                (Dot::DOT_WIDTH / 2) as f32,
+                (Dot::DOT_HEIGHT / 2) as f32,
+                DOT_VEL,
+            ),
+            scrolling_offset: 0.0,
+        })
+    }
+
+    fn tick(&mut self, ctx: &Context<'a>, dt: Duration) -> Result<()> {
+        self.dot.handle_input(ctx.input);
+        self.dot
+            .move_position(Rect::new(0, 0, LEVEL_WIDTH, LEVEL_HEIGHT), dt);
+
+        //Scroll background
+        let bg_width = self.assets.texture(&self.bg_texture)?.width() as f32;
+        self.scrolling_offset -= SCROLL_VEL * dt.as_secs_f32();
+        if self.scrolling_offset < -bg_width {
+            self.scrolling_offset += bg_width;
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, alpha: f32) -> Result<()> {
+        let dot_texture = self.assets.texture(&self.dot_texture)?;
+        let bg_texture = self.assets.texture(&self.bg_texture)?;
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        //Render background
+        let offset = self.scrolling_offset.round() as i32;
+        bg_texture.render(canvas, offset, 0, None)?;
+        bg_texture.render(canvas, offset + bg_texture.width() as i32, 0, None)?;
+
+        self.dot.render(&dot_texture, canvas, 0, 0, alpha)
+    }
+
+    fn hash_state(&self, state: &mut dyn Hasher) {
+        self.dot.hash_state(state);
+        state.write_u32(self.scrolling_offset.to_bits());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::assets::{AssetCache, ChunkHandle, TextureHandle};
+use crate::audio::{load_music, open_audio};
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::mixer::{Channel, Music};
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "sound_effects",
+    title: "Sound Effects and Music",
+    create: |ctx| Ok(Box::new(SoundEffects::init(ctx)?)),
+};
+
+pub struct SoundEffects<'a> {
+    assets: AssetCache<'a>,
+    texture: TextureHandle,
+    music: Music<'static>,
+    scratch: ChunkHandle,
+    high: ChunkHandle,
+    medium: ChunkHandle,
+    low: ChunkHandle,
+}
+
+impl<'a> Lesson<'a> for SoundEffects<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let assets = ctx.assets.clone();
+        let texture = assets.load_texture_with_color_key("resources/prompt.png", None)?;
+
+        open_audio()?;
+        Ok(Self {
+            texture,
+            music: load_music("resources/beat.wav")?,
+            scratch: assets.load_chunk("resources/scratch.wav")?,
+            high: assets.load_chunk("resources/high.wav")?,
+            medium: assets.load_chunk("resources/medium.wav")?,
+            low: assets.load_chunk("resources/low.wav")?,
+            assets,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        let input = ctx.input;
+        let channel = Channel::all();
+        for (action, chunk) in [
+            ("play_high", &self.high),
+            ("play_medium", &self.medium),
+            ("play_low", &self.low),
+            ("play_scratch", &self.scratch),
+        ] {
+            if input.just_pressed(action) {
+                let chunk = self.assets.chunk(chunk)?;
+                channel.play(&chunk, 0).map_err(Error::mixer)?;
+            }
+        }
+        if input.just_pressed("toggle_music") {
+            if !Music::is_playing() {
+                self.music.play(0).map_err(Error::mixer)?;
+            } else if Music::is_paused() {
+                Music::resume();
+            } else {
+                Music::pause();
+            }
+        }
+        if input.just_pressed("stop_music") {
+            Music::halt();
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.clear();
+        self.assets
+            .texture(&self.texture)?
+            .render(canvas, 0, 0, None)
+    }
+}
+
+impl Drop for SoundEffects<'_> {
+    fn drop(&mut self) {
+        Music::halt();
+        Channel::all().halt();
+    }
+}
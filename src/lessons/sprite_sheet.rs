@@ -0,0 +1,70 @@
+use crate::atlas::{Atlas, AtlasBuilder, Region};
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::sheet::Sheet;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "sprite_sheet",
+    title: "Sprite Sheet",
+    create: |ctx| Ok(Box::new(SpriteSheet::init(ctx)?)),
+};
+
+pub struct SpriteSheet<'a> {
+    atlas: Atlas<'a>,
+    sprite_clips: [Region; 4],
+}
+
+impl<'a> Lesson<'a> for SpriteSheet<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let path = "resources/dots.json";
+        let sheet = Sheet::load(path)?;
+        let clips = ["top_left", "top_right", "bottom_left", "bottom_right"]
+            .into_iter()
+            .map(|name| match sheet.frame(name) {
+                Some(frame) => Ok(frame.clip),
+                None => Err(Error::decode(path, format!("no frame named {name:?}"))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let atlas = AtlasBuilder::new()
+            .add_clips("dots", sheet.image(), clips)
+            .build(ctx.texture_creator)?;
+        let sprite_clips = atlas
+            .tiles("dots")?
+            .try_into()
+            .map_err(|e| Error::decode(path, e))?;
+        Ok(Self {
+            atlas,
+            sprite_clips,
+        })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        let [top_left, top_right, bottom_left, bottom_right] = self.sprite_clips;
+        self.atlas.render(canvas, top_left, 0, 0)?;
+        self.atlas.render(
+            canvas,
+            top_right,
+            (SCREEN_WIDTH - top_right.rect.width()) as i32,
+            0,
+        )?;
+        self.atlas.render(
+            canvas,
+            bottom_left,
+            0,
+            (SCREEN_HEIGHT - bottom_left.rect.height()) as i32,
+        )?;
+        self.atlas.render(
+            canvas,
+            bottom_right,
+            (SCREEN_WIDTH - bottom_right.rect.width()) as i32,
+            (SCREEN_HEIGHT - bottom_right.rect.height()) as i32,
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use crate::error::{check_asset, Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use sdl2::render::{Texture, WindowCanvas};
+use sdl2::surface::Surface;
+use std::path::Path;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "surfaces",
+    title: "Surfaces",
+    create: |ctx| Ok(Box::new(Surfaces::init(ctx)?)),
+};
+
+pub struct Surfaces<'a> {
+    texture: Texture<'a>,
+}
+
+impl<'a> Lesson<'a> for Surfaces<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let path = Path::new("resources/hello_world.bmp");
+        check_asset(path)?;
+        let surface = Surface::load_bmp(path).map_err(|e| Error::decode(path, e))?;
+
+        let texture = ctx
+            .texture_creator
+            .create_texture_from_surface(surface)
+            .map_err(Error::render)?;
+        Ok(Self { texture })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas
+            .copy(&self.texture, None, None)
+            .map_err(Error::render)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::error::Result;
+use crate::font::load_font;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use crate::SCREEN_WIDTH;
+use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+use sdl2::ttf::Font;
+use sdl2::VideoSubsystem;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "text_input_and_clipboard",
+    title: "Text Input and Clipboard Handling",
+    create: |ctx| Ok(Box::new(TextInputAndClipboard::init(ctx)?)),
+};
+
+pub struct TextInputAndClipboard<'a> {
+    font: Font<'a, 'static>,
+    prompt_text_texture: LTexture<'a>,
+    input_text_texture: LTexture<'a>,
+    input_text: String,
+    rendered_text: bool,
+    video: VideoSubsystem,
+}
+
+impl<'a> Lesson<'a> for TextInputAndClipboard<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = load_font(ctx.ttf_context, "resources/gnd.ttf", 32)?;
+
+        let prompt_text_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            "Enter Text:",
+            Color::RGB(0, 0, 0),
+        )?;
+
+        let input_text = "Some Text".to_string();
+        let input_text_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            &input_text,
+            Color::RGB(0, 0, 0),
+        )?;
+
+        ctx.video.text_input().start();
+
+        Ok(Self {
+            font,
+            prompt_text_texture,
+            input_text_texture,
+            input_text,
+            rendered_text: false,
+            video: ctx.video.clone(),
+        })
+    }
+
+    fn handle_event(&mut self, _ctx: &Context<'a>, event: &Event) -> Result<()> {
+        match event {
+            Event::KeyDown {
+                keycode, keymod, ..
+            } => {
+                if keycode.eq(&Some(Keycode::BACKSPACE)) && !self.input_text.is_empty() {
+                    self.input_text.pop();
+                    self.rendered_text = true;
+                } else if keycode.eq(&Some(Keycode::C)) && keymod.eq(&Mod::LCTRLMOD) {
+                    let _ = self.video.clipboard().set_clipboard_text(&self.input_text);
+                } else if keycode.eq(&Some(Keycode::V)) && keymod.eq(&Mod::LCTRLMOD) {
+                    if let Ok(tmp_text) = self.video.clipboard().clipboard_text() {
+                        self.input_text = tmp_text;
+                        self.rendered_text = true;
+                    }
+                }
+            }
+            Event::TextInput { text, .. } => {
+                self.input_text.push_str(text);
+                self.rendered_text = true;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        if self.rendered_text {
+            if self.input_text.is_empty() {
+                self.input_text = " ".to_string();
+            }
+
+            self.input_text_texture = LTexture::load_from_rendered_text(
+                ctx.texture_creator,
+                &self.font,
+                &self.input_text,
+                Color::RGB(0, 0, 0),
+            )?;
+            self.rendered_text = false;
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.prompt_text_texture.render(
+            canvas,
+            (SCREEN_WIDTH as i32 - self.prompt_text_texture.width() as i32) / 2,
+            0,
+            None,
+        )?;
+        self.input_text_texture.render(
+            canvas,
+            (SCREEN_WIDTH as i32 - self.input_text_texture.width() as i32) / 2,
+            self.input_text_texture.height() as i32,
+            None,
+        )
+    }
+}
+
+impl Drop for TextInputAndClipboard<'_> {
+    fn drop(&mut self) {
+        self.video.text_input().stop();
+    }
+}
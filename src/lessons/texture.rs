@@ -0,0 +1,28 @@
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::load_texture;
+use sdl2::render::{Texture, WindowCanvas};
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "texture",
+    title: "Texture",
+    create: |ctx| Ok(Box::new(TextureLesson::init(ctx)?)),
+};
+
+pub struct TextureLesson<'a> {
+    texture: Texture<'a>,
+}
+
+impl<'a> Lesson<'a> for TextureLesson<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            texture: load_texture(ctx.texture_creator, "resources/hello_world.bmp")?,
+        })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas
+            .copy(&self.texture, None, None)
+            .map_err(Error::render)
+    }
+}
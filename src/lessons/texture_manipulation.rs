@@ -0,0 +1,39 @@
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::pixels::TRANSPARENT;
+use crate::texture::{LTexture, DEFAULT_COLOR_KEY};
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "texture_manipulation",
+    title: "Texture Manipulation",
+    create: |ctx| Ok(Box::new(TextureManipulation::init(ctx)?)),
+};
+
+pub struct TextureManipulation<'a> {
+    background_texture: LTexture<'a>,
+    foo_texture: LTexture<'a>,
+}
+
+impl<'a> Lesson<'a> for TextureManipulation<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        //Clear the background by hand instead of color keying it
+        let mut foo_texture = LTexture::load_streaming(ctx.texture_creator, "resources/foo.png")?;
+        let mut pixels = foo_texture.lock()?;
+        pixels.replace_color(DEFAULT_COLOR_KEY, TRANSPARENT);
+        pixels.unlock()?;
+
+        Ok(Self {
+            background_texture: LTexture::load_from_file(
+                ctx.texture_creator,
+                "resources/background.png",
+            )?,
+            foo_texture,
+        })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        self.background_texture.render(canvas, 0, 0, None)?;
+        self.foo_texture.render(canvas, 240, 190, None)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error::Result;
+use crate::font::load_font;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+use sdl2::ttf::Font;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "timer",
+    title: "Timing",
+    create: |ctx| Ok(Box::new(Timer::init(ctx)?)),
+};
+
+pub struct Timer<'a> {
+    font: Font<'a, 'static>,
+    prompt_texture: LTexture<'a>,
+    time_text_texture: LTexture<'a>,
+    time_texture: LTexture<'a>,
+    start_time: u32,
+}
+
+impl<'a> Lesson<'a> for Timer<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = load_font(ctx.ttf_context, "resources/gnd.ttf", 32)?;
+
+        let prompt_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            &format!(
+                "Press {} to Reset Start Time.",
+                ctx.input.bindings().key_names("restart_timer")
+            ),
+            Color::RGB(0, 0, 255),
+        )?;
+        let time_text_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            "Seconds since start time: ",
+            Color::RGB(0, 0, 0),
+        )?;
+        let time_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &font,
+            "0",
+            Color::RGB(0, 0, 0),
+        )?;
+
+        Ok(Self {
+            font,
+            prompt_texture,
+            time_text_texture,
+            time_texture,
+            start_time: 0,
+        })
+    }
+
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        if ctx.input.just_pressed("restart_timer") {
+            self.start_time = ctx.timer.ticks();
+        }
+        self.time_texture = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &self.font,
+            &format!("{}", ctx.timer.ticks().wrapping_sub(self.start_time) / 1000),
+            Color::RGB(0, 0, 0),
+        )?;
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+        self.prompt_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.prompt_texture.width()) / 2) as i32,
+            100,
+            None,
+        )?;
+        let time_text_texture_y = (SCREEN_HEIGHT - self.time_text_texture.height()) / 2;
+        self.time_text_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.time_text_texture.width()) / 2) as i32,
+            (time_text_texture_y) as i32,
+            None,
+        )?;
+        self.time_texture.render(
+            canvas,
+            ((SCREEN_WIDTH - self.time_texture.width()) / 2) as i32,
+            (time_text_texture_y + self.time_text_texture.height() + 10) as i32,
+            None,
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use crate::assets::FontHandle;
+use crate::error::Result;
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::LTexture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::render::WindowCanvas;
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "ttf_fonts",
+    title: "True Type Fonts",
+    create: |ctx| Ok(Box::new(TtfFonts::init(ctx)?)),
+};
+
+const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+pub struct TtfFonts<'a> {
+    font: FontHandle,
+    //The font's generation the text was rendered with
+    generation: u32,
+    text: LTexture<'a>,
+}
+
+impl<'a> TtfFonts<'a> {
+    fn render_text(ctx: &Context<'a>, font: &FontHandle) -> Result<LTexture<'a>> {
+        LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &*ctx.assets.font(font)?,
+            TEXT,
+            Color::RGB(255, 0, 0),
+        )
+    }
+}
+
+impl<'a> Lesson<'a> for TtfFonts<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        let font = ctx.assets.load_font("resources/gnd.ttf", 26)?;
+        Ok(Self {
+            generation: ctx.assets.generation(&font),
+            text: Self::render_text(ctx, &font)?,
+            font,
+        })
+    }
+
+    //Render the text again if the font file was edited
+    fn update(&mut self, ctx: &Context<'a>) -> Result<()> {
+        let generation = ctx.assets.generation(&self.font);
+        if generation != self.generation {
+            self.text = Self::render_text(ctx, &self.font)?;
+            self.generation = generation;
+        }
+        Ok(())
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        self.text.render(
+            canvas,
+            (SCREEN_WIDTH - self.text.width()) as i32 / 2,
+            (SCREEN_HEIGHT - self.text.height()) as i32 / 2,
+            None,
+        )
+    }
+}
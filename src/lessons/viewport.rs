@@ -0,0 +1,62 @@
+use crate::error::{Error, Result};
+use crate::lesson::{Context, Lesson, LessonInfo};
+use crate::texture::load_texture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Texture, WindowCanvas};
+
+pub const LESSON: LessonInfo = LessonInfo {
+    name: "viewport",
+    title: "Viewport",
+    create: |ctx| Ok(Box::new(Viewport::init(ctx)?)),
+};
+
+pub struct Viewport<'a> {
+    texture: Texture<'a>,
+}
+
+impl<'a> Lesson<'a> for Viewport<'a> {
+    fn init(ctx: &Context<'a>) -> Result<Self> {
+        Ok(Self {
+            texture: load_texture(ctx.texture_creator, "resources/viewport.png")?,
+        })
+    }
+
+    fn render(&self, canvas: &mut WindowCanvas, _alpha: f32) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(255, 255, 255));
+        canvas.clear();
+
+        let top_left_viewport = Rect::new(0, 0, SCREEN_WIDTH / 2, SCREEN_HEIGHT / 2);
+        canvas.set_viewport(top_left_viewport);
+        canvas
+            .copy(&self.texture, None, None)
+            .map_err(Error::render)?;
+
+        let top_right_viewport = Rect::new(
+            (SCREEN_WIDTH / 2) as i32,
+            0,
+            SCREEN_WIDTH / 2,
+            SCREEN_HEIGHT / 2,
+        );
+        canvas.set_viewport(top_right_viewport);
+        canvas
+            .copy(&self.texture, None, None)
+            .map_err(Error::render)?;
+
+        let bottom_viewport = Rect::new(
+            0,
+            (SCREEN_HEIGHT / 2) as i32,
+            SCREEN_WIDTH,
+            SCREEN_HEIGHT / 2,
+        );
+        canvas.set_viewport(bottom_viewport);
+        canvas
+            .copy(&self.texture, None, None)
+            .map_err(Error::render)?;
+
+        //Restore the full window for whatever renders next
+        canvas.set_viewport(None);
+        Ok(())
+    }
+}
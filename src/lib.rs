@@ -0,0 +1,47 @@
+pub mod animation;
+pub mod app;
+pub mod assets;
+pub mod atlas;
+pub mod audio;
+pub mod bitmap_font;
+pub mod capture;
+pub mod clock;
+pub mod collision;
+pub mod controller;
+pub mod dot;
+pub mod error;
+pub mod font;
+pub mod fps;
+pub mod glyph_atlas;
+pub mod harness;
+pub mod input;
+pub mod lesson;
+pub mod lessons;
+pub mod menu;
+pub mod nine_slice;
+pub mod pixels;
+pub mod render_queue;
+pub mod replay;
+pub mod sheet;
+pub mod texture;
+pub mod timer;
+pub mod timestep;
+
+pub use animation::{AnimatedSprite, Animation, Playback};
+pub use assets::{AssetCache, ChunkHandle, FontHandle, Reload, TextureHandle};
+pub use bitmap_font::BitmapFont;
+pub use clock::{Clock, ManualClock, SdlClock};
+pub use dot::Dot;
+pub use error::{Error, Result};
+pub use fps::FpsCounter;
+pub use glyph_atlas::GlyphAtlas;
+pub use input::{Bindings, Input};
+pub use lesson::{Context, Lesson, LessonInfo};
+pub use nine_slice::NineSlice;
+pub use pixels::Pixels;
+pub use render_queue::{Depth, RenderQueue, RenderStats};
+pub use texture::{LTexture, PixelLock, Transform};
+pub use timer::LTimer;
+
+pub const SCREEN_WIDTH: u32 = 640;
+pub const SCREEN_HEIGHT: u32 = 480;
@@ -0,0 +1,134 @@
+use lazyfoo::capture::FrameCapture;
+use lazyfoo::harness::{self, Script};
+use lazyfoo::replay::{Recorder, Recording, Session};
+use lazyfoo::{app, lessons, Error, LessonInfo};
+use std::env;
+use std::ops::Range;
+use std::process::ExitCode;
+
+const USAGE: &str =
+    "usage: launcher [lesson [--record <file>] [capture]] | launcher --replay <file> [capture]
+capture: --capture <file.gif|dir> [--frames <first>..<end>] [--headless]";
+
+//Frames captured unless --frames says otherwise: two seconds
+const DEFAULT_CAPTURE_FRAMES: Range<u32> = 0..120;
+
+//Without a lesson name the menu of all lessons is shown. --headless runs the
+//lesson without a window, with no input, until the captured frames are saved.
+fn main() -> Result<ExitCode, Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (start, options) = match args[..] {
+        [] => {
+            app::launch(lessons::ALL)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        ["--replay", path, ref options @ ..] => (Start::Replay(path), options),
+        [name, ref options @ ..] if !name.starts_with("--") => (Start::Lesson(name), options),
+        _ => return Ok(usage()),
+    };
+    let Some(options) = Options::parse(options) else {
+        return Ok(usage());
+    };
+
+    let (info, session) = match start {
+        //A replay has its own input, and only a live lesson can be recorded
+        Start::Replay(_) if options.record.is_some() || options.headless => return Ok(usage()),
+        Start::Replay(path) => {
+            let recording = Recording::load(path)?;
+            let Some(info) = find_lesson(&recording.lesson) else {
+                return Ok(ExitCode::from(2));
+            };
+            (info, Session::Replay(recording))
+        }
+        Start::Lesson(name) => {
+            let Some(info) = find_lesson(name) else {
+                return Ok(ExitCode::from(2));
+            };
+            match options.record {
+                Some(path) => (info, Session::Record(Recorder::create(path, info.name)?)),
+                None => (info, Session::Live),
+            }
+        }
+    };
+    let capture = options
+        .capture
+        .map(|path| FrameCapture::new(path, options.frames.unwrap_or(DEFAULT_CAPTURE_FRAMES)));
+
+    if options.headless {
+        let Some(mut capture) = capture else {
+            return Ok(usage());
+        };
+        let frames = capture.frames().end;
+        harness::run_headless(info, frames, &Script::new(), |_, canvas| {
+            capture.frame(canvas)
+        })?;
+        app::finish_capture(Some(capture))?;
+    } else {
+        app::run(info, session, capture)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+enum Start<'a> {
+    Lesson(&'a str),
+    Replay(&'a str),
+}
+
+#[derive(Default)]
+struct Options<'a> {
+    record: Option<&'a str>,
+    capture: Option<&'a str>,
+    frames: Option<Range<u32>>,
+    headless: bool,
+}
+
+impl<'a> Options<'a> {
+    fn parse(mut args: &[&'a str]) -> Option<Self> {
+        let mut options = Self::default();
+        while let [flag, rest @ ..] = args {
+            args = rest;
+            if *flag == "--headless" {
+                options.headless = true;
+                continue;
+            }
+            let [value, rest @ ..] = args else {
+                return None;
+            };
+            args = rest;
+            match *flag {
+                "--record" => options.record = Some(value),
+                "--capture" => options.capture = Some(value),
+                "--frames" => {
+                    let (first, end) = value.split_once("..")?;
+                    options.frames = Some(first.parse().ok()?..end.parse().ok()?);
+                }
+                _ => return None,
+            }
+        }
+        //Frame ranges and headless runs only mean something when capturing,
+        //and a headless run has no input to record
+        if options.capture.is_none() && (options.frames.is_some() || options.headless)
+            || options.headless && options.record.is_some()
+        {
+            return None;
+        }
+        Some(options)
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::from(2)
+}
+
+fn find_lesson(name: &str) -> Option<&'static LessonInfo> {
+    let info = lessons::by_name(name);
+    if info.is_none() {
+        eprintln!("unknown lesson `{name}`, expected one of:");
+        for info in lessons::ALL {
+            eprintln!("    {}", info.name);
+        }
+    }
+    info
+}
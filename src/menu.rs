@@ -0,0 +1,156 @@
+use crate::error::{Error, Result};
+use crate::font::load_font;
+use crate::lesson::{Context, LessonInfo};
+use crate::texture::LTexture;
+use crate::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use sdl2::event::Event;
+use sdl2::keyboard::Keycode;
+use sdl2::mouse::MouseButton;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::WindowCanvas;
+
+const TEXT_COLOR: Color = Color::RGB(0, 0, 0);
+const HIGHLIGHT_COLOR: Color = Color::RGB(0xa0, 0xc8, 0xff);
+const LIST_TOP: i32 = 64;
+const ROW_HEIGHT: i32 = 26;
+const VISIBLE_ROWS: usize = ((SCREEN_HEIGHT as i32 - LIST_TOP) / ROW_HEIGHT) as usize;
+
+/// Scrollable list of lessons. Arrow keys, the mouse wheel or hovering move
+/// the selection; Enter or a click starts the selected lesson.
+pub struct Menu<'a> {
+    lessons: &'static [&'static LessonInfo],
+    heading: LTexture<'a>,
+    items: Vec<LTexture<'a>>,
+    selected: usize,
+    scroll: usize,
+}
+
+impl<'a> Menu<'a> {
+    pub fn new(ctx: &Context<'a>, lessons: &'static [&'static LessonInfo]) -> Result<Self> {
+        let heading_font = load_font(ctx.ttf_context, "resources/lazy.ttf", 28)?;
+        let item_font = load_font(ctx.ttf_context, "resources/lazy.ttf", 18)?;
+
+        let heading = LTexture::load_from_rendered_text(
+            ctx.texture_creator,
+            &heading_font,
+            "Lazy Foo' SDL Tutorials",
+            TEXT_COLOR,
+        )?;
+        let items = lessons
+            .iter()
+            .enumerate()
+            .map(|(i, info)| {
+                LTexture::load_from_rendered_text(
+                    ctx.texture_creator,
+                    &item_font,
+                    &format!("{:02}  {}", i + 1, info.title),
+                    TEXT_COLOR,
+                )
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            lessons,
+            heading,
+            items,
+            selected: 0,
+            scroll: 0,
+        })
+    }
+
+    /// Returns the lesson to start, if `event` picked one.
+    pub fn handle_event(&mut self, event: &Event) -> Option<&'static LessonInfo> {
+        match *event {
+            Event::KeyDown {
+                keycode: Some(keycode),
+                ..
+            } => match keycode {
+                Keycode::Up => self.select(self.selected.saturating_sub(1)),
+                Keycode::Down => self.select(self.selected + 1),
+                Keycode::PageUp => self.select(self.selected.saturating_sub(VISIBLE_ROWS)),
+                Keycode::PageDown => self.select(self.selected + VISIBLE_ROWS),
+                Keycode::Home => self.select(0),
+                Keycode::End => self.select(self.lessons.len()),
+                Keycode::Return | Keycode::KpEnter => return Some(self.lessons[self.selected]),
+                _ => {}
+            },
+            Event::MouseWheel { y, .. } => {
+                let max_scroll = self.lessons.len().saturating_sub(VISIBLE_ROWS);
+                self.scroll = self
+                    .scroll
+                    .saturating_add_signed(-y as isize)
+                    .min(max_scroll);
+                self.selected = self
+                    .selected
+                    .clamp(self.scroll, self.scroll + VISIBLE_ROWS - 1);
+            }
+            Event::MouseMotion { y, .. } => {
+                if let Some(row) = self.row_at(y) {
+                    self.selected = row;
+                }
+            }
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Left,
+                y,
+                ..
+            } => {
+                if let Some(row) = self.row_at(y) {
+                    self.selected = row;
+                    return Some(self.lessons[row]);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    pub fn render(&self, canvas: &mut WindowCanvas) -> Result<()> {
+        canvas.set_draw_color(Color::RGB(0xff, 0xff, 0xff));
+        canvas.clear();
+
+        self.heading.render(
+            canvas,
+            (SCREEN_WIDTH - self.heading.width()) as i32 / 2,
+            (LIST_TOP - self.heading.height() as i32) / 2,
+            None,
+        )?;
+
+        let visible = self.items.iter().enumerate().skip(self.scroll);
+        for (row, (i, item)) in visible.take(VISIBLE_ROWS).enumerate() {
+            let y = LIST_TOP + row as i32 * ROW_HEIGHT;
+            if i == self.selected {
+                canvas.set_draw_color(HIGHLIGHT_COLOR);
+                canvas
+                    .fill_rect(Rect::new(0, y, SCREEN_WIDTH, ROW_HEIGHT as u32))
+                    .map_err(Error::render)?;
+            }
+            item.render(
+                canvas,
+                40,
+                y + (ROW_HEIGHT - item.height() as i32) / 2,
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    //Moves the selection, scrolling just enough to keep it on screen
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(self.lessons.len() - 1);
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + VISIBLE_ROWS {
+            self.scroll = self.selected + 1 - VISIBLE_ROWS;
+        }
+    }
+
+    fn row_at(&self, y: i32) -> Option<usize> {
+        if y < LIST_TOP {
+            return None;
+        }
+        let row = ((y - LIST_TOP) / ROW_HEIGHT) as usize;
+        let index = self.scroll + row;
+        (row < VISIBLE_ROWS && index < self.lessons.len()).then_some(index)
+    }
+}
@@ -0,0 +1,270 @@
+//! Images cut into a 3x3 grid so they can be drawn at any size: corners stay
+//! as they are, edges and the center fill the space between them. Used for
+//! panels and buttons that fit their content.
+
+use crate::error::Result;
+use crate::texture::LTexture;
+use sdl2::rect::Rect;
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::video::WindowContext;
+use std::path::Path;
+
+/// How wide each border of the image is, in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Insets {
+    pub left: u32,
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+}
+
+impl Insets {
+    pub fn new(left: u32, top: u32, right: u32, bottom: u32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// The same width on every side.
+    pub fn uniform(width: u32) -> Self {
+        Self::new(width, width, width, width)
+    }
+}
+
+/// How an edge or the center covers more room than it has pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Fill {
+    #[default]
+    Stretch,
+    /// Repeats the pixels, cutting the last copy short.
+    Tile,
+}
+
+//A run of pixels along one axis
+#[derive(Clone, Copy)]
+struct Span {
+    start: i32,
+    len: u32,
+}
+
+impl Span {
+    fn split(self, before: u32, after: u32) -> [Span; 3] {
+        let middle = self.len - before - after;
+        [
+            Span {
+                start: self.start,
+                len: before,
+            },
+            Span {
+                start: self.start + before as i32,
+                len: middle,
+            },
+            Span {
+                start: self.start + (before + middle) as i32,
+                len: after,
+            },
+        ]
+    }
+}
+
+/// Where to cut an image and how to fill the space between the corners.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Slices {
+    insets: Insets,
+    edges: Fill,
+    center: Fill,
+}
+
+impl Slices {
+    pub fn new(insets: Insets) -> Self {
+        Self {
+            insets,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_edges(mut self, edges: Fill) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    pub fn with_center(mut self, center: Fill) -> Self {
+        self.center = center;
+        self
+    }
+
+    pub fn insets(&self) -> Insets {
+        self.insets
+    }
+
+    /// The size that fits `content` inside the borders.
+    pub fn fit(&self, content_width: u32, content_height: u32) -> (u32, u32) {
+        (
+            content_width + self.insets.left + self.insets.right,
+            content_height + self.insets.top + self.insets.bottom,
+        )
+    }
+
+    /// Each piece of `source` to copy and where it goes to cover `dest`.
+    /// Borders wider than `source` are cut down to fit it; if `dest` is too
+    /// small for the corners, they shrink in proportion.
+    pub fn pieces(&self, source: Rect, dest: Rect) -> Vec<(Rect, Rect)> {
+        let Insets {
+            left,
+            top,
+            right,
+            bottom,
+        } = self.insets;
+        let columns = bands(
+            Span {
+                start: source.x(),
+                len: source.width(),
+            },
+            Span {
+                start: dest.x(),
+                len: dest.width(),
+            },
+            left,
+            right,
+        );
+        let rows = bands(
+            Span {
+                start: source.y(),
+                len: source.height(),
+            },
+            Span {
+                start: dest.y(),
+                len: dest.height(),
+            },
+            top,
+            bottom,
+        );
+
+        let mut pieces = Vec::new();
+        for (row, &(source_y, dest_y)) in rows.iter().enumerate() {
+            for (column, &(source_x, dest_x)) in columns.iter().enumerate() {
+                let fill = match (row, column) {
+                    (1, 1) => self.center,
+                    (1, _) | (_, 1) => self.edges,
+                    _ => Fill::Stretch,
+                };
+                for (sy, dy) in fill_span(source_y, dest_y, fill) {
+                    for &(sx, dx) in &fill_span(source_x, dest_x, fill) {
+                        pieces.push((
+                            Rect::new(sx.start, sy.start, sx.len, sy.len),
+                            Rect::new(dx.start, dy.start, dx.len, dy.len),
+                        ));
+                    }
+                }
+            }
+        }
+        pieces
+    }
+}
+
+//Cuts both spans into before, middle and after
+fn bands(source: Span, dest: Span, before: u32, after: u32) -> [(Span, Span); 3] {
+    let before = before.min(source.len);
+    let after = after.min(source.len - before);
+    let (dest_before, dest_after) = if before + after <= dest.len {
+        (before, after)
+    } else {
+        let shrunk = (before as u64 * dest.len as u64 / (before + after) as u64) as u32;
+        (shrunk, dest.len - shrunk)
+    };
+
+    let source = source.split(before, after);
+    let dest = dest.split(dest_before, dest_after);
+    [
+        (source[0], dest[0]),
+        (source[1], dest[1]),
+        (source[2], dest[2]),
+    ]
+}
+
+//The copies that cover `dest` with `source`, leaving out empty ones
+fn fill_span(source: Span, dest: Span, fill: Fill) -> Vec<(Span, Span)> {
+    if source.len == 0 || dest.len == 0 {
+        return Vec::new();
+    }
+    match fill {
+        Fill::Stretch => vec![(source, dest)],
+        Fill::Tile => {
+            let mut copies = Vec::new();
+            let mut covered = 0;
+            while covered < dest.len {
+                let len = source.len.min(dest.len - covered);
+                copies.push((
+                    Span {
+                        start: source.start,
+                        len,
+                    },
+                    Span {
+                        start: dest.start + covered as i32,
+                        len,
+                    },
+                ));
+                covered += len;
+            }
+            copies
+        }
+    }
+}
+
+/// A texture drawn in nine slices, so one image makes a panel or button of
+/// any size.
+pub struct NineSlice<'a> {
+    texture: LTexture<'a>,
+    slices: Slices,
+}
+
+impl<'a> NineSlice<'a> {
+    pub fn new(texture: LTexture<'a>, insets: Insets) -> Self {
+        Self {
+            texture,
+            slices: Slices::new(insets),
+        }
+    }
+
+    pub fn load(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        path: impl AsRef<Path>,
+        insets: Insets,
+    ) -> Result<Self> {
+        let texture = LTexture::load_from_file(texture_creator, path)?;
+        Ok(Self::new(texture, insets))
+    }
+
+    /// Changes how the slices fill with the builder methods of [`Slices`].
+    pub fn with_slices(mut self, change: impl FnOnce(Slices) -> Slices) -> Self {
+        self.slices = change(self.slices);
+        self
+    }
+
+    pub fn slices(&self) -> &Slices {
+        &self.slices
+    }
+
+    pub fn set_color(&mut self, r: u8, g: u8, b: u8) {
+        self.texture.set_color(r, g, b);
+    }
+
+    pub fn set_alpha(&mut self, alpha: u8) {
+        self.texture.set_alpha(alpha);
+    }
+
+    /// Draws `clip` of the texture, or all of it, sliced to cover `dest`.
+    /// The insets are measured from the edges of `clip`, so one texture can
+    /// hold several images cut the same way.
+    pub fn render(&self, canvas: &mut WindowCanvas, clip: Option<Rect>, dest: Rect) -> Result<()> {
+        let source =
+            clip.unwrap_or_else(|| Rect::new(0, 0, self.texture.width(), self.texture.height()));
+        for (clip, to) in self.slices.pieces(source, dest) {
+            self.texture.render_to(canvas, Some(clip), to)?;
+        }
+        Ok(())
+    }
+}
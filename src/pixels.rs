@@ -0,0 +1,171 @@
+//! CPU-side copies of image pixels that can be edited one pixel at a time
+//! and uploaded to a streaming [`LTexture`](crate::texture::LTexture).
+
+use crate::error::{Error, Result};
+use sdl2::pixels::{Color, PixelFormatEnum};
+use sdl2::surface::Surface;
+
+/// The layout of [`Pixels`]: one `u32` per pixel, `0xRRGGBBAA`.
+pub const PIXEL_FORMAT: PixelFormatEnum = PixelFormatEnum::RGBA8888;
+
+/// Fully transparent black, for clearing pixels.
+pub const TRANSPARENT: Color = Color::RGBA(0, 0, 0, 0);
+
+/// A `width` by `height` image stored row by row in [`PIXEL_FORMAT`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pixels {
+    width: u32,
+    height: u32,
+    data: Vec<u32>,
+}
+
+impl Pixels {
+    /// An image with every pixel set to `color`.
+    pub fn new(width: u32, height: u32, color: Color) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![pack(color); width as usize * height as usize],
+        }
+    }
+
+    /// Copies a surface of any format, converting it to [`PIXEL_FORMAT`].
+    pub fn from_surface(surface: &Surface) -> Result<Self> {
+        let surface = surface
+            .convert_format(PIXEL_FORMAT)
+            .map_err(Error::render)?;
+        let (width, height) = surface.size();
+        let pitch = surface.pitch() as usize;
+        let row = width as usize * 4;
+        let data = surface.with_lock(|bytes| {
+            bytes
+                .chunks(pitch)
+                .take(height as usize)
+                .flat_map(|line| line[..row].chunks_exact(4))
+                .map(|pixel| u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]))
+                .collect()
+        });
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The pixels as `0xRRGGBBAA` words, row by row.
+    pub fn raw(&self) -> &[u32] {
+        &self.data
+    }
+
+    pub fn raw_mut(&mut self) -> &mut [u32] {
+        &mut self.data
+    }
+
+    /// The pixels in memory order for uploading, `width * 4` bytes a row.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.data
+            .iter()
+            .flat_map(|pixel| pixel.to_ne_bytes())
+            .collect()
+    }
+
+    /// The pixel at (`x`, `y`), or `None` outside the image.
+    pub fn get(&self, x: i32, y: i32) -> Option<Color> {
+        self.index(x, y).map(|i| unpack(self.data[i]))
+    }
+
+    /// Sets the pixel at (`x`, `y`); does nothing outside the image.
+    pub fn set(&mut self, x: i32, y: i32, color: Color) {
+        if let Some(i) = self.index(x, y) {
+            self.data[i] = pack(color);
+        }
+    }
+
+    /// Changes only the alpha of the pixel at (`x`, `y`).
+    pub fn set_alpha(&mut self, x: i32, y: i32, alpha: u8) {
+        if let Some(i) = self.index(x, y) {
+            self.data[i] = (self.data[i] & !0xff) | alpha as u32;
+        }
+    }
+
+    /// Replaces every pixel of exactly `from` with `to` and returns how many
+    /// changed.
+    pub fn replace_color(&mut self, from: Color, to: Color) -> usize {
+        let (from, to) = (pack(from), pack(to));
+        let mut replaced = 0;
+        for pixel in self.data.iter_mut().filter(|pixel| **pixel == from) {
+            *pixel = to;
+            replaced += 1;
+        }
+        replaced
+    }
+
+    /// Fills the area of matching color around (`x`, `y`), joined through
+    /// edges rather than corners, with `color`. Returns how many pixels changed.
+    pub fn flood_fill(&mut self, x: i32, y: i32, color: Color) -> usize {
+        let Some(start) = self.index(x, y) else {
+            return 0;
+        };
+        let (target, fill) = (self.data[start], pack(color));
+        if target == fill {
+            return 0;
+        }
+
+        let mut filled = 0;
+        let mut pending = vec![(x, y)];
+        while let Some((x, y)) = pending.pop() {
+            match self.index(x, y) {
+                Some(i) if self.data[i] == target => {
+                    self.data[i] = fill;
+                    filled += 1;
+                    pending.extend([(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]);
+                }
+                _ => {}
+            }
+        }
+        filled
+    }
+
+    /// Copies `source` over this image with its top left corner at (`x`, `y`),
+    /// leaving out whatever falls outside.
+    pub fn copy_from(&mut self, source: &Pixels, x: i32, y: i32) {
+        for row in 0..source.height as i32 {
+            for column in 0..source.width as i32 {
+                if let Some(i) = self.index(x + column, y + row) {
+                    self.data[i] = source.data[(row * source.width as i32 + column) as usize];
+                }
+            }
+        }
+    }
+
+    /// Sets every pixel to what `f` makes of its position and color.
+    pub fn map(&mut self, mut f: impl FnMut(u32, u32, Color) -> Color) {
+        let width = self.width.max(1) as usize;
+        for (i, pixel) in self.data.iter_mut().enumerate() {
+            let (x, y) = ((i % width) as u32, (i / width) as u32);
+            *pixel = pack(f(x, y, unpack(*pixel)));
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let (x, y) = (u32::try_from(x).ok()?, u32::try_from(y).ok()?);
+        (x < self.width && y < self.height).then(|| y as usize * self.width as usize + x as usize)
+    }
+}
+
+fn pack(color: Color) -> u32 {
+    u32::from_be_bytes([color.r, color.g, color.b, color.a])
+}
+
+fn unpack(pixel: u32) -> Color {
+    let [r, g, b, a] = pixel.to_be_bytes();
+    Color::RGBA(r, g, b, a)
+}
@@ -0,0 +1,259 @@
+//! Draw commands collected over a frame and drawn together in depth order,
+//! so what ends up in front no longer depends on which code ran last.
+
+use crate::error::{Error, Result};
+use crate::texture::{LTexture, Transform};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::WindowCanvas;
+use std::collections::HashMap;
+
+/// Where a command is drawn relative to the others: layers in increasing
+/// order, and by `z` within a layer. Commands at the same depth are grouped
+/// by texture, in the order each texture was first queued, so overlapping
+/// ones should be given different depths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Depth {
+    pub layer: i32,
+    pub z: i32,
+}
+
+impl Depth {
+    pub fn new(layer: i32, z: i32) -> Self {
+        Self { layer, z }
+    }
+
+    /// Everything on `layer` at the same depth.
+    pub fn layer(layer: i32) -> Self {
+        Self::new(layer, 0)
+    }
+
+    /// Sorted by the bottom edge of `rect`, so things lower on screen are
+    /// drawn over things behind them.
+    pub fn y_sorted(layer: i32, rect: Rect) -> Self {
+        Self::new(layer, rect.bottom())
+    }
+}
+
+/// Something to draw once the queue is flushed.
+pub enum Command<'t> {
+    Sprite {
+        texture: &'t LTexture<'t>,
+        clip: Option<Rect>,
+        dest: Rect,
+        transform: Option<Transform>,
+    },
+    FillRect {
+        rect: Rect,
+        color: Color,
+    },
+    DrawRect {
+        rect: Rect,
+        color: Color,
+    },
+    Line {
+        from: Point,
+        to: Point,
+        color: Color,
+    },
+}
+
+impl Command<'_> {
+    //Which texture the command draws with; primitives draw with none
+    fn texture_id(&self) -> Option<usize> {
+        match self {
+            Command::Sprite { texture, .. } => Some(texture.id()),
+            _ => None,
+        }
+    }
+
+    fn draw(&self, canvas: &mut WindowCanvas) -> Result<()> {
+        match *self {
+            Command::Sprite {
+                texture,
+                clip,
+                dest,
+                ref transform,
+            } => texture.copy(canvas, clip, dest, transform.as_ref()),
+            Command::FillRect { rect, color } => {
+                canvas.set_draw_color(color);
+                canvas.fill_rect(rect).map_err(Error::render)
+            }
+            Command::DrawRect { rect, color } => {
+                canvas.set_draw_color(color);
+                canvas.draw_rect(rect).map_err(Error::render)
+            }
+            Command::Line { from, to, color } => {
+                canvas.set_draw_color(color);
+                canvas.draw_line(from, to).map_err(Error::render)
+            }
+        }
+    }
+}
+
+/// Text that can be drawn as one sprite per glyph.
+pub trait GlyphSource {
+    fn glyph_texture(&self) -> &LTexture<'_>;
+
+    /// Calls `place` with the clip of every glyph of `text` and where it goes
+    /// relative to where the text starts.
+    fn place_glyphs(&self, text: &str, place: &mut dyn FnMut(Rect, Point));
+}
+
+/// What drawing a frame took.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    pub commands: usize,
+    /// Runs of copies from one texture, or of primitives. SDL sends each run
+    /// to the GPU as a single draw call.
+    pub draw_calls: usize,
+    /// How often a copy used a different texture than the copy before it.
+    pub texture_switches: usize,
+}
+
+struct Queued<'t> {
+    depth: Depth,
+    texture_id: Option<usize>,
+    //Which texture this frame's command uses, numbered as they were first queued
+    batch: Option<usize>,
+    command: Command<'t>,
+}
+
+/// Commands for one frame, drawn in [`Depth`] order by [`RenderQueue::flush`].
+#[derive(Default)]
+pub struct RenderQueue<'t> {
+    commands: Vec<Queued<'t>>,
+    //Texture ids are addresses, which change from run to run, so draws are
+    //grouped by batch number instead to come out in the same order every time
+    batches: HashMap<usize, usize>,
+}
+
+impl<'t> RenderQueue<'t> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn push(&mut self, depth: Depth, command: Command<'t>) {
+        let texture_id = command.texture_id();
+        let next = self.batches.len();
+        let batch = texture_id.map(|id| *self.batches.entry(id).or_insert(next));
+        self.commands.push(Queued {
+            depth,
+            texture_id,
+            batch,
+            command,
+        });
+    }
+
+    /// Queues what [`LTexture::render`] would draw.
+    pub fn sprite(
+        &mut self,
+        depth: Depth,
+        texture: &'t LTexture<'t>,
+        x: i32,
+        y: i32,
+        clip: Option<Rect>,
+    ) {
+        let dest = texture.dest_rect(x, y, clip, 1.0);
+        self.sprite_to(depth, texture, clip, dest);
+    }
+
+    /// Queues what [`LTexture::render_ex`] would draw.
+    pub fn sprite_ex(
+        &mut self,
+        depth: Depth,
+        texture: &'t LTexture<'t>,
+        x: i32,
+        y: i32,
+        clip: Option<Rect>,
+        transform: Transform,
+    ) {
+        let dest = texture.dest_rect(x, y, clip, transform.scale);
+        self.push(
+            depth,
+            Command::Sprite {
+                texture,
+                clip,
+                dest,
+                transform: Some(transform),
+            },
+        );
+    }
+
+    /// Queues what [`LTexture::render_to`] would draw.
+    pub fn sprite_to(
+        &mut self,
+        depth: Depth,
+        texture: &'t LTexture<'t>,
+        clip: Option<Rect>,
+        dest: Rect,
+    ) {
+        self.push(
+            depth,
+            Command::Sprite {
+                texture,
+                clip,
+                dest,
+                transform: None,
+            },
+        );
+    }
+
+    /// Queues `text` with its top left corner at (`x`, `y`), every glyph at
+    /// the same depth.
+    pub fn text(&mut self, depth: Depth, font: &'t impl GlyphSource, x: i32, y: i32, text: &str) {
+        let texture = font.glyph_texture();
+        font.place_glyphs(text, &mut |clip, at| {
+            self.sprite(depth, texture, x + at.x(), y + at.y(), Some(clip));
+        });
+    }
+
+    pub fn fill_rect(&mut self, depth: Depth, rect: Rect, color: Color) {
+        self.push(depth, Command::FillRect { rect, color });
+    }
+
+    pub fn draw_rect(&mut self, depth: Depth, rect: Rect, color: Color) {
+        self.push(depth, Command::DrawRect { rect, color });
+    }
+
+    pub fn line(&mut self, depth: Depth, from: Point, to: Point, color: Color) {
+        self.push(depth, Command::Line { from, to, color });
+    }
+
+    /// Draws everything queued, back to front, and empties the queue.
+    pub fn flush(&mut self, canvas: &mut WindowCanvas) -> Result<RenderStats> {
+        //Stable, so commands sharing a depth and texture keep their order
+        self.commands
+            .sort_by_key(|queued| (queued.depth, queued.batch));
+        self.batches.clear();
+
+        let mut stats = RenderStats {
+            commands: self.commands.len(),
+            ..RenderStats::default()
+        };
+        let mut previous: Option<Option<usize>> = None;
+        let mut last_texture = None;
+        for queued in self.commands.drain(..) {
+            if previous != Some(queued.texture_id) {
+                stats.draw_calls += 1;
+            }
+            if let Some(id) = queued.texture_id {
+                if last_texture.is_some_and(|last| last != id) {
+                    stats.texture_switches += 1;
+                }
+                last_texture = Some(id);
+            }
+            previous = Some(queued.texture_id);
+            queued.command.draw(canvas)?;
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,543 @@
+//! Recording and replaying a lesson's input.
+//!
+//! A recording holds, for every frame, the time that frame advanced the
+//! simulation by, the input events it handled and a checksum of the lesson's
+//! state afterwards. Replaying feeds the same time and events back through the
+//! same loop, so any checksum mismatch means the simulation diverged.
+//!
+//! Only events lessons can react to are recorded: quit, keyboard, text,
+//! mouse and game controller events, without timestamps, window ids or
+//! scancodes.
+
+use crate::error::{Error, Result};
+use crate::lesson::Lesson;
+use sdl2::controller::{Axis, Button};
+use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::{MouseButton, MouseState, MouseWheelDirection};
+use std::fs::{self, File};
+use std::hash::Hasher;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const MAGIC: &[u8] = b"LFREC\x01";
+
+/// How a lesson is being driven.
+pub enum Session {
+    /// Live input and time.
+    Live,
+    /// Live input and time, written to a recording as it happens.
+    Record(Recorder),
+    /// Input and time read back from a recording.
+    Replay(Recording),
+}
+
+/// One frame of a recording.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub elapsed: Duration,
+    pub events: Vec<Event>,
+    pub checksum: u64,
+}
+
+/// A whole recording, as read back for replay.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recording {
+    /// Name of the recorded lesson.
+    pub lesson: String,
+    pub frames: Vec<Frame>,
+}
+
+impl Recording {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| Error::decode(path, e))?;
+        Self::decode(&bytes).map_err(|e| Error::decode(path, e))
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(malformed("not a lesson recording"));
+        }
+        let len = reader.uvarint()? as usize;
+        let lesson = reader.string(len)?;
+
+        let mut frames = Vec::new();
+        while reader.pos < bytes.len() {
+            frames.push(reader.frame()?);
+        }
+        Ok(Self { lesson, frames })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = header(&self.lesson);
+        for frame in &self.frames {
+            encode_frame(&mut out, frame.elapsed, &frame.events, frame.checksum);
+        }
+        out
+    }
+}
+
+/// Writes a recording frame by frame, so it survives the program dying mid-run.
+pub struct Recorder {
+    path: PathBuf,
+    file: File,
+}
+
+impl Recorder {
+    pub fn create(path: impl AsRef<Path>, lesson: &str) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = File::create(&path).map_err(|e| Error::encode(&path, e))?;
+        file.write_all(&header(lesson))
+            .map_err(|e| Error::encode(&path, e))?;
+        Ok(Self { path, file })
+    }
+
+    pub fn write_frame(
+        &mut self,
+        elapsed: Duration,
+        events: &[Event],
+        checksum: u64,
+    ) -> Result<()> {
+        let mut out = Vec::new();
+        encode_frame(&mut out, elapsed, events, checksum);
+        self.file
+            .write_all(&out)
+            .map_err(|e| Error::encode(&self.path, e))
+    }
+}
+
+/// Whether `event` is kept in recordings.
+pub fn is_recordable(event: &Event) -> bool {
+    encode_event(&mut Vec::new(), event)
+}
+
+/// Checksum of the state `lesson` reports through [`Lesson::hash_state`].
+pub fn checksum(lesson: &dyn Lesson<'_>) -> u64 {
+    let mut checksum = Checksum::default();
+    lesson.hash_state(&mut checksum);
+    checksum.finish()
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is the same in every build,
+/// so recordings can be checked by a different binary than made them.
+pub struct Checksum(u64);
+
+impl Default for Checksum {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Checksum {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn header(lesson: &str) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    write_uvarint(&mut out, lesson.len() as u64);
+    out.extend_from_slice(lesson.as_bytes());
+    out
+}
+
+fn encode_frame(out: &mut Vec<u8>, elapsed: Duration, events: &[Event], checksum: u64) {
+    write_uvarint(out, elapsed.as_nanos() as u64);
+    out.extend_from_slice(&checksum.to_le_bytes());
+
+    let mut encoded = Vec::new();
+    let count = events
+        .iter()
+        .filter(|event| encode_event(&mut encoded, event))
+        .count();
+    write_uvarint(out, count as u64);
+    out.extend_from_slice(&encoded);
+}
+
+//Appends `event` and returns true, or returns false if it isn't recorded
+fn encode_event(out: &mut Vec<u8>, event: &Event) -> bool {
+    match *event {
+        Event::Quit { .. } => out.push(0),
+        Event::KeyDown {
+            keycode,
+            keymod,
+            repeat,
+            ..
+        }
+        | Event::KeyUp {
+            keycode,
+            keymod,
+            repeat,
+            ..
+        } => {
+            out.push(if matches!(event, Event::KeyDown { .. }) {
+                1
+            } else {
+                2
+            });
+            //0 stands for None, as SDL never uses it for a real key
+            write_ivarint(out, keycode.map_or(0, |k| k.into_i32()).into());
+            write_uvarint(out, keymod.bits().into());
+            out.push(repeat.into());
+        }
+        Event::TextInput { ref text, .. } => {
+            out.push(3);
+            write_uvarint(out, text.len() as u64);
+            out.extend_from_slice(text.as_bytes());
+        }
+        Event::MouseMotion {
+            which,
+            mousestate,
+            x,
+            y,
+            xrel,
+            yrel,
+            ..
+        } => {
+            out.push(4);
+            write_uvarint(out, which.into());
+            write_uvarint(out, mousestate.to_sdl_state().into());
+            for value in [x, y, xrel, yrel] {
+                write_ivarint(out, value.into());
+            }
+        }
+        Event::MouseButtonDown {
+            which,
+            mouse_btn,
+            clicks,
+            x,
+            y,
+            ..
+        }
+        | Event::MouseButtonUp {
+            which,
+            mouse_btn,
+            clicks,
+            x,
+            y,
+            ..
+        } => {
+            let down = matches!(event, Event::MouseButtonDown { .. });
+            out.push(if down { 5 } else { 6 });
+            write_uvarint(out, which.into());
+            out.extend_from_slice(&[mouse_btn as u8, clicks]);
+            write_ivarint(out, x.into());
+            write_ivarint(out, y.into());
+        }
+        Event::MouseWheel {
+            which,
+            x,
+            y,
+            direction,
+            precise_x,
+            precise_y,
+            mouse_x,
+            mouse_y,
+            ..
+        } => {
+            out.push(7);
+            write_uvarint(out, which.into());
+            write_uvarint(out, direction.to_ll().into());
+            for value in [x, y, mouse_x, mouse_y] {
+                write_ivarint(out, value.into());
+            }
+            out.extend_from_slice(&precise_x.to_le_bytes());
+            out.extend_from_slice(&precise_y.to_le_bytes());
+        }
+        Event::ControllerAxisMotion {
+            which, axis, value, ..
+        } => {
+            out.push(8);
+            write_uvarint(out, which.into());
+            out.push(axis as u8);
+            write_ivarint(out, value.into());
+        }
+        Event::ControllerButtonDown { which, button, .. }
+        | Event::ControllerButtonUp { which, button, .. } => {
+            let down = matches!(event, Event::ControllerButtonDown { .. });
+            out.push(if down { 9 } else { 10 });
+            write_uvarint(out, which.into());
+            out.push(button as u8);
+        }
+        Event::ControllerDeviceAdded { which, .. } => {
+            out.push(11);
+            write_uvarint(out, which.into());
+        }
+        Event::ControllerDeviceRemoved { which, .. } => {
+            out.push(12);
+            write_uvarint(out, which.into());
+        }
+        _ => return false,
+    }
+    true
+}
+
+const AXES: [Axis; 6] = [
+    Axis::LeftX,
+    Axis::LeftY,
+    Axis::RightX,
+    Axis::RightY,
+    Axis::TriggerLeft,
+    Axis::TriggerRight,
+];
+
+const BUTTONS: [Button; 21] = [
+    Button::A,
+    Button::B,
+    Button::X,
+    Button::Y,
+    Button::Back,
+    Button::Guide,
+    Button::Start,
+    Button::LeftStick,
+    Button::RightStick,
+    Button::LeftShoulder,
+    Button::RightShoulder,
+    Button::DPadUp,
+    Button::DPadDown,
+    Button::DPadLeft,
+    Button::DPadRight,
+    Button::Misc1,
+    Button::Paddle1,
+    Button::Paddle2,
+    Button::Paddle3,
+    Button::Paddle4,
+    Button::Touchpad,
+];
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len());
+        let Some(end) = end else {
+            return Err(malformed(format!(
+                "recording ends early at byte {}",
+                self.pos
+            )));
+        };
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn uvarint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(malformed(format!("varint too long at byte {}", self.pos)))
+    }
+
+    fn ivarint(&mut self) -> Result<i64> {
+        let value = self.uvarint()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    //Reads a varint that must fit in `T`
+    fn varint<T: TryFrom<i64>>(&mut self) -> Result<T> {
+        let value = self.ivarint()?;
+        T::try_from(value).map_err(|_| malformed(format!("value {value} out of range")))
+    }
+
+    fn unsigned<T: TryFrom<u64>>(&mut self) -> Result<T> {
+        let value = self.uvarint()?;
+        T::try_from(value).map_err(|_| malformed(format!("value {value} out of range")))
+    }
+
+    fn string(&mut self, len: usize) -> Result<String> {
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|e| malformed(e.to_string()))
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        let bytes = self.take(4)?;
+        Ok(f32::from_le_bytes(bytes.try_into().expect("took 4 bytes")))
+    }
+
+    fn frame(&mut self) -> Result<Frame> {
+        let elapsed = Duration::from_nanos(self.uvarint()?);
+        let checksum = u64::from_le_bytes(self.take(8)?.try_into().expect("took 8 bytes"));
+        let count = self.uvarint()?;
+        let events = (0..count).map(|_| self.event()).collect::<Result<_>>()?;
+        Ok(Frame {
+            elapsed,
+            events,
+            checksum,
+        })
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        let tag = self.byte()?;
+        let event = match tag {
+            0 => Event::Quit { timestamp: 0 },
+            1 | 2 => {
+                let keycode = Keycode::from_i32(self.varint()?);
+                let keymod = Mod::from_bits_truncate(self.unsigned()?);
+                let repeat = self.byte()? != 0;
+                if tag == 1 {
+                    Event::KeyDown {
+                        timestamp: 0,
+                        window_id: 0,
+                        keycode,
+                        scancode: None,
+                        keymod,
+                        repeat,
+                    }
+                } else {
+                    Event::KeyUp {
+                        timestamp: 0,
+                        window_id: 0,
+                        keycode,
+                        scancode: None,
+                        keymod,
+                        repeat,
+                    }
+                }
+            }
+            3 => {
+                let len = self.unsigned()?;
+                Event::TextInput {
+                    timestamp: 0,
+                    window_id: 0,
+                    text: self.string(len)?,
+                }
+            }
+            4 => Event::MouseMotion {
+                timestamp: 0,
+                window_id: 0,
+                which: self.unsigned()?,
+                mousestate: MouseState::from_sdl_state(self.unsigned()?),
+                x: self.varint()?,
+                y: self.varint()?,
+                xrel: self.varint()?,
+                yrel: self.varint()?,
+            },
+            5 | 6 => {
+                let which = self.unsigned()?;
+                let mouse_btn = MouseButton::from_ll(self.byte()?);
+                let clicks = self.byte()?;
+                let (x, y) = (self.varint()?, self.varint()?);
+                if tag == 5 {
+                    Event::MouseButtonDown {
+                        timestamp: 0,
+                        window_id: 0,
+                        which,
+                        mouse_btn,
+                        clicks,
+                        x,
+                        y,
+                    }
+                } else {
+                    Event::MouseButtonUp {
+                        timestamp: 0,
+                        window_id: 0,
+                        which,
+                        mouse_btn,
+                        clicks,
+                        x,
+                        y,
+                    }
+                }
+            }
+            7 => Event::MouseWheel {
+                timestamp: 0,
+                window_id: 0,
+                which: self.unsigned()?,
+                direction: MouseWheelDirection::from_ll(self.unsigned()?),
+                x: self.varint()?,
+                y: self.varint()?,
+                mouse_x: self.varint()?,
+                mouse_y: self.varint()?,
+                precise_x: self.f32()?,
+                precise_y: self.f32()?,
+            },
+            8 => Event::ControllerAxisMotion {
+                timestamp: 0,
+                which: self.unsigned()?,
+                axis: lookup(&AXES, self.byte()?, |axis| axis as u8)?,
+                value: self.varint()?,
+            },
+            9 | 10 => {
+                let which = self.unsigned()?;
+                let button = lookup(&BUTTONS, self.byte()?, |button| button as u8)?;
+                if tag == 9 {
+                    Event::ControllerButtonDown {
+                        timestamp: 0,
+                        which,
+                        button,
+                    }
+                } else {
+                    Event::ControllerButtonUp {
+                        timestamp: 0,
+                        which,
+                        button,
+                    }
+                }
+            }
+            11 => Event::ControllerDeviceAdded {
+                timestamp: 0,
+                which: self.unsigned()?,
+            },
+            12 => Event::ControllerDeviceRemoved {
+                timestamp: 0,
+                which: self.unsigned()?,
+            },
+            _ => {
+                return Err(malformed(format!(
+                    "unknown event tag {tag} at byte {}",
+                    self.pos - 1
+                )))
+            }
+        };
+        Ok(event)
+    }
+}
+
+fn lookup<T: Copy>(all: &[T], code: u8, code_of: impl Fn(T) -> u8) -> Result<T> {
+    all.iter()
+        .copied()
+        .find(|&item| code_of(item) == code)
+        .ok_or_else(|| malformed(format!("unknown controller code {code}")))
+}
+
+fn malformed(reason: impl Into<String>) -> Error {
+    Error::BadRecording {
+        reason: reason.into(),
+    }
+}
+
+fn write_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+//Zigzag, so small negative numbers stay short too
+fn write_ivarint(out: &mut Vec<u8>, value: i64) {
+    write_uvarint(out, ((value << 1) ^ (value >> 63)) as u64);
+}
@@ -0,0 +1,290 @@
+//! Sprite sheet metadata exported by TexturePacker (JSON hash or array) and
+//! Aseprite, so frame rectangles and animations live next to the image
+//! instead of in code.
+
+use crate::animation::{Animation, AnimationFrame, Playback};
+use crate::error::{check_asset, Error, Result};
+use sdl2::rect::{Point, Rect};
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long a frame shows when the sheet doesn't say; Aseprite's default.
+pub const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+/// One named frame of a sheet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SheetFrame {
+    pub name: String,
+    /// Where the frame is in the image.
+    pub clip: Rect,
+    /// Where trimmed frames go within their untrimmed size; (0, 0) if untrimmed.
+    pub offset: Point,
+    pub duration: Duration,
+}
+
+/// Order an Aseprite tag plays its frames in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Forward,
+    Reverse,
+    #[serde(rename = "pingpong")]
+    PingPong,
+    #[serde(rename = "pingpong_reverse")]
+    PingPongReverse,
+}
+
+/// A named run of frames, `from` to `to` inclusive, as tagged in Aseprite.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameTag {
+    pub name: String,
+    pub from: usize,
+    pub to: usize,
+    pub direction: Direction,
+    /// How many times the tag plays, if limited.
+    pub repeat: Option<u32>,
+}
+
+/// Frames and tags of a sprite sheet image.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawSheet")]
+pub struct Sheet {
+    image: PathBuf,
+    frames: Vec<SheetFrame>,
+    tags: Vec<FrameTag>,
+}
+
+impl Sheet {
+    /// Reads a sheet from a JSON file. The image path is taken relative to it.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let text = fs::read_to_string(path).map_err(|e| Error::decode(path, e))?;
+        let mut sheet = Self::parse(&text).map_err(|e| Error::decode(path, e))?;
+        if let Some(dir) = path.parent() {
+            sheet.image = dir.join(&sheet.image);
+        }
+        Ok(sheet)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    pub fn image(&self) -> &Path {
+        &self.image
+    }
+
+    /// Every frame, in the order the file lists them.
+    pub fn frames(&self) -> &[SheetFrame] {
+        &self.frames
+    }
+
+    pub fn frame(&self, name: &str) -> Option<&SheetFrame> {
+        self.frames.iter().find(|frame| frame.name == name)
+    }
+
+    pub fn tags(&self) -> &[FrameTag] {
+        &self.tags
+    }
+
+    /// Builds the animation tagged `tag`. Reverse directions play the frames
+    /// backwards; a tag that repeats once plays once, any other count loops.
+    pub fn animation(&self, tag: &str) -> Result<Animation> {
+        let tag =
+            self.tags
+                .iter()
+                .find(|t| t.name == tag)
+                .ok_or_else(|| Error::MissingAnimation {
+                    name: tag.to_string(),
+                })?;
+
+        let mut frames: Vec<_> = self.frames[tag.from..=tag.to]
+            .iter()
+            .map(animation_frame)
+            .collect();
+        if matches!(
+            tag.direction,
+            Direction::Reverse | Direction::PingPongReverse
+        ) {
+            frames.reverse();
+        }
+        let playback = match (tag.direction, tag.repeat) {
+            (Direction::PingPong | Direction::PingPongReverse, _) => Playback::PingPong,
+            (_, Some(1)) => Playback::Once,
+            _ => Playback::Loop,
+        };
+        Ok(Animation::new(frames, playback))
+    }
+
+    /// Plays every frame of the sheet in order, for sheets without tags.
+    pub fn all_frames(&self, playback: Playback) -> Animation {
+        Animation::new(self.frames.iter().map(animation_frame), playback)
+    }
+}
+
+fn animation_frame(frame: &SheetFrame) -> AnimationFrame {
+    AnimationFrame {
+        clip: frame.clip,
+        duration: frame.duration,
+    }
+}
+
+//The files as written; both tools share this layout
+#[derive(Deserialize)]
+struct RawSheet {
+    frames: RawFrames,
+    meta: RawMeta,
+}
+
+#[derive(Deserialize)]
+struct RawMeta {
+    image: PathBuf,
+    #[serde(default, rename = "frameTags")]
+    frame_tags: Vec<RawTag>,
+}
+
+#[derive(Deserialize)]
+struct RawRect {
+    x: i32,
+    y: i32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct RawFrame {
+    //Only in the array format; the hash format keys frames by name instead
+    filename: Option<String>,
+    frame: RawRect,
+    #[serde(default)]
+    rotated: bool,
+    #[serde(rename = "spriteSourceSize")]
+    sprite_source_size: Option<RawRect>,
+    //Milliseconds, written by Aseprite only
+    duration: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct RawTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default = "forward")]
+    direction: Direction,
+    //Aseprite writes the count as a string
+    repeat: Option<String>,
+}
+
+fn forward() -> Direction {
+    Direction::Forward
+}
+
+//Frames from either a JSON array or a JSON object, keeping the file's order
+//either way since that is the animation order
+struct RawFrames(Vec<(Option<String>, RawFrame)>);
+
+impl<'de> Deserialize<'de> for RawFrames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = RawFrames;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an array or map of frames")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RawFrames, A::Error> {
+                let mut frames = Vec::new();
+                while let Some(frame) = seq.next_element::<RawFrame>()? {
+                    frames.push((None, frame));
+                }
+                Ok(RawFrames(frames))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<RawFrames, A::Error> {
+                let mut frames = Vec::new();
+                while let Some((name, frame)) = map.next_entry::<String, RawFrame>()? {
+                    frames.push((Some(name), frame));
+                }
+                Ok(RawFrames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor)
+    }
+}
+
+impl TryFrom<RawSheet> for Sheet {
+    type Error = String;
+
+    fn try_from(raw: RawSheet) -> Result<Self, String> {
+        let frames = raw
+            .frames
+            .0
+            .into_iter()
+            .enumerate()
+            .map(|(i, (key, frame))| {
+                let name = key.or(frame.filename).unwrap_or_else(|| i.to_string());
+                if frame.rotated {
+                    return Err(format!("frame {name:?} is rotated, which is not supported"));
+                }
+                let offset = frame
+                    .sprite_source_size
+                    .map_or(Point::new(0, 0), |source| Point::new(source.x, source.y));
+                Ok(SheetFrame {
+                    name,
+                    clip: Rect::new(frame.frame.x, frame.frame.y, frame.frame.w, frame.frame.h),
+                    offset,
+                    duration: frame
+                        .duration
+                        .map_or(DEFAULT_FRAME_DURATION, Duration::from_millis),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if frames.is_empty() {
+            return Err("sprite sheet has no frames".to_string());
+        }
+
+        let tags = raw
+            .meta
+            .frame_tags
+            .into_iter()
+            .map(|tag| {
+                if tag.from > tag.to || tag.to >= frames.len() {
+                    return Err(format!(
+                        "tag {:?} spans frames {} to {} of {}",
+                        tag.name,
+                        tag.from,
+                        tag.to,
+                        frames.len()
+                    ));
+                }
+                let repeat = match tag.repeat.as_deref() {
+                    None | Some("") | Some("0") => None,
+                    Some(count) => Some(count.parse().map_err(|_| {
+                        format!("tag {:?} has a repeat count of {count:?}", tag.name)
+                    })?),
+                };
+                Ok(FrameTag {
+                    name: tag.name,
+                    from: tag.from,
+                    to: tag.to,
+                    direction: tag.direction,
+                    repeat,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            image: raw.meta.image,
+            frames,
+            tags,
+        })
+    }
+}
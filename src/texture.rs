@@ -0,0 +1,358 @@
+use crate::error::{check_asset, Error, Result};
+use crate::pixels::{Pixels, PIXEL_FORMAT, TRANSPARENT};
+use sdl2::image::{LoadSurface, LoadTexture};
+use sdl2::pixels::{Color, PixelFormatEnum};
+use sdl2::rect::{Point, Rect};
+use sdl2::render::{BlendMode, Texture, TextureAccess, TextureCreator, WindowCanvas};
+use sdl2::surface::Surface;
+use sdl2::ttf::Font;
+use sdl2::video::WindowContext;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+
+/// Color keyed out of images loaded with [`LTexture::load_from_file`].
+pub const DEFAULT_COLOR_KEY: Color = Color::RGB(0, 0xff, 0xff);
+
+/// Rotation, scaling and flipping applied by [`LTexture::render_ex`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub angle: f64,
+    pub center: Option<Point>,
+    /// Size multiplier; the image still starts at the given position.
+    pub scale: f64,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            angle: 0.0,
+            center: None,
+            scale: 1.0,
+            flip_horizontal: false,
+            flip_vertical: false,
+        }
+    }
+}
+
+pub struct LTexture<'a> {
+    texture: Texture<'a>,
+    width: u32,
+    height: u32,
+    //What a streaming texture shows, kept so it can be edited
+    pixels: Option<Pixels>,
+}
+
+impl<'a> LTexture<'a> {
+    pub fn new(texture: Texture<'a>) -> Self {
+        let width = texture.query().width;
+        let height = texture.query().height;
+        Self {
+            texture,
+            width,
+            height,
+            pixels: None,
+        }
+    }
+
+    /// A streaming texture showing `pixels`, which can be edited through
+    /// [`LTexture::lock`]. Alpha blending is on.
+    pub fn from_pixels(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        pixels: Pixels,
+    ) -> Result<Self> {
+        let texture = texture_creator
+            .create_texture_streaming(PIXEL_FORMAT, pixels.width(), pixels.height())
+            .map_err(Error::render)?;
+        let mut texture = Self {
+            pixels: Some(pixels),
+            ..Self::new(texture)
+        };
+        texture.set_blend_mode(BlendMode::Blend);
+        texture.upload()?;
+        Ok(texture)
+    }
+
+    /// Loads an image into a streaming texture as is, without color keying.
+    pub fn load_streaming(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let surface = Surface::from_file(path).map_err(|e| Error::decode(path, e))?;
+        Self::from_pixels(texture_creator, Pixels::from_surface(&surface)?)
+    }
+
+    pub fn load_from_file(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        Self::load_from_file_with_color_key(texture_creator, path, Some(DEFAULT_COLOR_KEY))
+    }
+
+    pub fn load_from_file_with_color_key(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        path: impl AsRef<Path>,
+        color_key: Option<Color>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        check_asset(path)?;
+        let mut surface = Surface::from_file(path).map_err(|e| Error::decode(path, e))?;
+        if let Some(color_key) = color_key {
+            surface
+                .set_color_key(true, color_key)
+                .map_err(|e| Error::decode(path, e))?;
+        }
+        let texture = texture_creator
+            .create_texture_from_surface(surface)
+            .map_err(Error::render)?;
+        Ok(Self::new(texture))
+    }
+
+    /// An empty RGBA texture. Create it with [`TextureAccess::Target`] to draw
+    /// into it with [`LTexture::with_target`], or [`TextureAccess::Streaming`]
+    /// to edit its pixels with [`LTexture::lock`].
+    pub fn create_blank(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        width: u32,
+        height: u32,
+        access: TextureAccess,
+    ) -> Result<Self> {
+        if access == TextureAccess::Streaming {
+            return Self::from_pixels(texture_creator, Pixels::new(width, height, TRANSPARENT));
+        }
+        let texture = texture_creator
+            .create_texture(PIXEL_FORMAT, access, width, height)
+            .map_err(Error::render)?;
+        Ok(Self::new(texture))
+    }
+
+    pub fn load_from_rendered_text(
+        texture_creator: &'a TextureCreator<WindowContext>,
+        font: &Font,
+        text: &str,
+        color: Color,
+    ) -> Result<Self> {
+        let text_surface = font.render(text).blended(color).map_err(Error::font)?;
+
+        let text_texture = texture_creator
+            .create_texture_from_surface(&text_surface)
+            .map_err(Error::render)?;
+
+        Ok(Self::new(text_texture))
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn format(&self) -> PixelFormatEnum {
+        self.texture.query().format
+    }
+
+    pub fn set_color(&mut self, r: u8, g: u8, b: u8) {
+        self.texture.set_color_mod(r, g, b);
+    }
+
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.texture.set_blend_mode(blend_mode);
+    }
+
+    pub fn set_alpha(&mut self, alpha: u8) {
+        self.texture.set_alpha_mod(alpha);
+    }
+
+    /// The pixels of a streaming texture.
+    pub fn pixels(&self) -> Option<&Pixels> {
+        self.pixels.as_ref()
+    }
+
+    /// Opens a streaming texture's pixels for editing. Changes are sent to
+    /// the texture when the lock is [unlocked](PixelLock::unlock) or dropped.
+    pub fn lock(&mut self) -> Result<PixelLock<'_, 'a>> {
+        if self.pixels.is_none() {
+            return Err(Error::render("only streaming textures can be locked"));
+        }
+        Ok(PixelLock {
+            texture: self,
+            uploaded: false,
+        })
+    }
+
+    fn upload(&mut self) -> Result<()> {
+        let Some(pixels) = &self.pixels else {
+            return Ok(());
+        };
+        let row = pixels.width() as usize * 4;
+        let bytes = pixels.to_bytes();
+        self.texture
+            .with_lock(None, |buffer, pitch| {
+                for (line, source) in buffer.chunks_mut(pitch).zip(bytes.chunks(row)) {
+                    line[..row].copy_from_slice(source);
+                }
+            })
+            .map_err(Error::render)
+    }
+
+    //Keeps color, alpha and blending set on `other` when it is replaced by this
+    pub(crate) fn copy_modulation(&mut self, other: &Self) {
+        let (r, g, b) = other.texture.color_mod();
+        self.texture.set_color_mod(r, g, b);
+        self.texture.set_alpha_mod(other.texture.alpha_mod());
+        self.texture.set_blend_mode(other.texture.blend_mode());
+    }
+
+    /// Runs `draw` with the canvas drawing into this texture instead of the
+    /// window, which is restored afterwards. Only works on textures created
+    /// with [`TextureAccess::Target`].
+    pub fn with_target(
+        &mut self,
+        canvas: &mut WindowCanvas,
+        draw: impl FnOnce(&mut WindowCanvas) -> Result<()>,
+    ) -> Result<()> {
+        let mut result = Ok(());
+        canvas
+            .with_texture_canvas(&mut self.texture, |target| result = draw(target))
+            .map_err(Error::render)?;
+        result
+    }
+
+    pub fn render(
+        &self,
+        canvas: &mut WindowCanvas,
+        x: i32,
+        y: i32,
+        clip: Option<Rect>,
+    ) -> Result<()> {
+        self.copy(canvas, clip, self.dest_rect(x, y, clip, 1.0), None)
+    }
+
+    /// Draws `clip` of the texture, or all of it, stretched over `dest`.
+    pub fn render_to(
+        &self,
+        canvas: &mut WindowCanvas,
+        clip: Option<Rect>,
+        dest: Rect,
+    ) -> Result<()> {
+        self.copy(canvas, clip, dest, None)
+    }
+
+    pub fn render_ex(
+        &self,
+        canvas: &mut WindowCanvas,
+        x: i32,
+        y: i32,
+        clip: Option<Rect>,
+        transform: Transform,
+    ) -> Result<()> {
+        let dest = self.dest_rect(x, y, clip, transform.scale);
+        self.copy(canvas, clip, dest, Some(&transform))
+    }
+
+    //Copies to `dest`, rotating and flipping by `transform` but leaving its scale to `dest`
+    pub(crate) fn copy(
+        &self,
+        canvas: &mut WindowCanvas,
+        clip: Option<Rect>,
+        dest: Rect,
+        transform: Option<&Transform>,
+    ) -> Result<()> {
+        match transform {
+            None => canvas.copy(&self.texture, clip, dest),
+            Some(transform) => canvas.copy_ex(
+                &self.texture,
+                clip,
+                dest,
+                transform.angle,
+                transform.center,
+                transform.flip_horizontal,
+                transform.flip_vertical,
+            ),
+        }
+        .map_err(Error::render)
+    }
+
+    //Tells textures apart, so draws can be grouped by the texture they use
+    pub(crate) fn id(&self) -> usize {
+        self.texture.raw() as usize
+    }
+
+    pub(crate) fn dest_rect(&self, x: i32, y: i32, clip: Option<Rect>, scale: f64) -> Rect {
+        let (width, height) = match clip {
+            Some(rect) => (rect.width(), rect.height()),
+            None => (self.width, self.height),
+        };
+        Rect::new(
+            x,
+            y,
+            (width as f64 * scale).round() as u32,
+            (height as f64 * scale).round() as u32,
+        )
+    }
+}
+
+/// A streaming texture's pixels, open for editing. The edits are sent to
+/// the texture when the lock is dropped, so the texture always shows what
+/// [`LTexture::pixels`] holds.
+pub struct PixelLock<'t, 'a> {
+    texture: &'t mut LTexture<'a>,
+    uploaded: bool,
+}
+
+impl PixelLock<'_, '_> {
+    /// Sends the edited pixels to the texture, reporting if that fails,
+    /// which dropping the lock can't.
+    pub fn unlock(mut self) -> Result<()> {
+        self.uploaded = true;
+        self.texture.upload()
+    }
+}
+
+impl Drop for PixelLock<'_, '_> {
+    //A lock dropped early, e.g. by `?`, still uploads; a failure is reported and otherwise ignored
+    fn drop(&mut self) {
+        if !self.uploaded {
+            if let Err(e) = self.texture.upload() {
+                eprintln!("{e}");
+            }
+        }
+    }
+}
+
+impl Deref for PixelLock<'_, '_> {
+    type Target = Pixels;
+
+    fn deref(&self) -> &Pixels {
+        self.texture
+            .pixels
+            .as_ref()
+            .expect("only streaming textures lock")
+    }
+}
+
+impl DerefMut for PixelLock<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Pixels {
+        self.texture
+            .pixels
+            .as_mut()
+            .expect("only streaming textures lock")
+    }
+}
+
+/// Loads an image straight into a texture, without color keying.
+pub fn load_texture<'a>(
+    texture_creator: &'a TextureCreator<WindowContext>,
+    path: impl AsRef<Path>,
+) -> Result<Texture<'a>> {
+    let path = path.as_ref();
+    check_asset(path)?;
+    texture_creator
+        .load_texture(path)
+        .map_err(|e| Error::decode(path, e))
+}
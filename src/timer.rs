@@ -0,0 +1,148 @@
+use crate::clock::Clock;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Stopwatch with pause, time scaling and child timers.
+///
+/// Time comes from a [`Clock`], or for a [`child`](Self::child) from its
+/// parent's scaled time, so pausing or
+/// slowing a parent pauses or slows every timer under it.
+pub struct LTimer {
+    state: Rc<RefCell<TimerState>>,
+}
+
+enum Source {
+    Clock(Box<dyn Clock>),
+    Parent(Rc<RefCell<TimerState>>),
+}
+
+struct TimerState {
+    source: Source,
+    started: bool,
+    paused: bool,
+    time_scale: f64,
+    //Scaled time banked before the current running segment
+    banked: Duration,
+    //Source time when the current running segment began
+    segment_start: Duration,
+}
+
+impl LTimer {
+    pub fn new(clock: impl Clock + 'static) -> Self {
+        Self::with_source(Source::Clock(Box::new(clock)))
+    }
+
+    fn with_source(source: Source) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(TimerState {
+                source,
+                started: false,
+                paused: false,
+                time_scale: 1.0,
+                banked: Duration::ZERO,
+                segment_start: Duration::ZERO,
+            })),
+        }
+    }
+
+    /// Creates a timer that runs on this timer's time: it only advances while
+    /// this one is started and unpaused, and is scaled by its time scale.
+    pub fn child(&self) -> Self {
+        Self::with_source(Source::Parent(Rc::clone(&self.state)))
+    }
+
+    pub fn start(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.started = true;
+        state.paused = false;
+        state.banked = Duration::ZERO;
+        state.segment_start = state.source_now();
+    }
+
+    pub fn stop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.started = false;
+        state.paused = false;
+        state.banked = Duration::ZERO;
+        state.segment_start = Duration::ZERO;
+    }
+
+    pub fn pause(&mut self) {
+        let mut state = self.state.borrow_mut();
+        if state.started && !state.paused {
+            state.banked = state.elapsed();
+            state.paused = true;
+        }
+    }
+
+    pub fn unpause(&mut self) {
+        let mut state = self.state.borrow_mut();
+        if state.started && state.paused {
+            state.paused = false;
+            state.segment_start = state.source_now();
+        }
+    }
+
+    /// Scaled time since `start`, not counting time spent paused.
+    pub fn get_ticks(&self) -> Duration {
+        self.state.borrow().elapsed()
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.state.borrow().time_scale
+    }
+
+    /// Sets how fast this timer runs relative to its source: 0.5 is
+    /// slow motion, 2.0 double speed. Time already elapsed is unaffected.
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        let mut state = self.state.borrow_mut();
+        if state.started && !state.paused {
+            state.banked = state.elapsed();
+            state.segment_start = state.source_now();
+        }
+        state.time_scale = time_scale.max(0.0);
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.state.borrow().started
+    }
+
+    /// Whether this timer, or any timer it is a child of, is paused or stopped
+    /// after being started.
+    pub fn is_paused(&self) -> bool {
+        self.state.borrow().is_paused()
+    }
+}
+
+impl TimerState {
+    fn source_now(&self) -> Duration {
+        match &self.source {
+            Source::Clock(clock) => clock.now(),
+            Source::Parent(parent) => parent.borrow().elapsed(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        if !self.started {
+            return Duration::ZERO;
+        }
+        if self.paused {
+            return self.banked;
+        }
+        //A parent restarted behind our back can make its time go backwards
+        let running = self.source_now().saturating_sub(self.segment_start);
+        self.banked + running.mul_f64(self.time_scale)
+    }
+
+    fn is_paused(&self) -> bool {
+        let parent_paused = match &self.source {
+            Source::Clock(_) => false,
+            Source::Parent(parent) => {
+                let parent = parent.borrow();
+                parent.is_paused() || !parent.started
+            }
+        };
+        self.started && (self.paused || parent_paused)
+    }
+}
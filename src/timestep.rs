@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+/// Simulation ticks per second used by the lesson runner.
+pub const TICKS_PER_SECOND: u32 = 60;
+
+/// Default cap on ticks run in a single frame.
+pub const MAX_FRAME_SKIP: u32 = 5;
+
+/// Turns variable frame times into a whole number of fixed simulation ticks.
+///
+/// Frame time is added to an accumulator and spent one `step` at a time;
+/// whatever is left over becomes the interpolation [`alpha`](Self::alpha)
+/// used to draw between the previous and the current tick.
+#[derive(Clone, Debug)]
+pub struct FixedTimestep {
+    step: Duration,
+    max_frame_skip: u32,
+    accumulator: Duration,
+}
+
+impl FixedTimestep {
+    pub fn new(ticks_per_second: u32) -> Self {
+        Self {
+            step: Duration::from_secs(1) / ticks_per_second,
+            max_frame_skip: MAX_FRAME_SKIP,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    /// Caps the ticks run per frame so a long stall (a breakpoint, a window
+    /// drag) doesn't make the simulation spiral trying to catch up.
+    pub fn with_max_frame_skip(mut self, max_frame_skip: u32) -> Self {
+        self.max_frame_skip = max_frame_skip.max(1);
+        self
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Adds `elapsed` frame time and returns how many ticks to run now.
+    /// Time beyond the frame-skip cap is dropped.
+    pub fn advance(&mut self, elapsed: Duration) -> u32 {
+        self.accumulator += elapsed;
+
+        let mut ticks = 0;
+        while self.accumulator >= self.step {
+            if ticks == self.max_frame_skip {
+                self.accumulator = Duration::ZERO;
+                break;
+            }
+            self.accumulator -= self.step;
+            ticks += 1;
+        }
+        ticks
+    }
+
+    /// How far between the last tick and the next one the current frame is, in `0.0..1.0`.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+
+    pub fn reset(&mut self) {
+        self.accumulator = Duration::ZERO;
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(TICKS_PER_SECOND)
+    }
+}
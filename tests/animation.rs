@@ -0,0 +1,142 @@
+use lazyfoo::animation::AnimationFrame;
+use lazyfoo::{Animation, Playback};
+use sdl2::rect::Rect;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+const FRAME_TIME: Duration = Duration::from_millis(100);
+
+fn clips(count: i32) -> Vec<Rect> {
+    (0..count).map(|i| Rect::new(i * 64, 0, 64, 205)).collect()
+}
+
+//Frame indices seen after each of `steps` advances of `dt`
+fn indices(animation: &mut Animation, dt: Duration, steps: usize) -> Vec<usize> {
+    (0..steps)
+        .map(|_| {
+            animation.advance(dt);
+            animation.frame_index()
+        })
+        .collect()
+}
+
+fn counter(animation: &mut Animation) -> Rc<Cell<u32>> {
+    let count = Rc::new(Cell::new(0));
+    let seen = Rc::clone(&count);
+    animation.on_finished(move || seen.set(seen.get() + 1));
+    count
+}
+
+#[test]
+fn loop_wraps_to_the_first_frame() {
+    let mut animation = Animation::uniform(clips(3), FRAME_TIME, Playback::Loop);
+    assert_eq!(animation.frame_index(), 0);
+    assert_eq!(
+        indices(&mut animation, FRAME_TIME, 7),
+        [1, 2, 0, 1, 2, 0, 1]
+    );
+    assert_eq!(animation.current_clip(), Rect::new(64, 0, 64, 205));
+}
+
+#[test]
+fn advances_by_time_not_by_call() {
+    let mut animation = Animation::uniform(clips(4), FRAME_TIME, Playback::Loop);
+    assert_eq!(
+        indices(&mut animation, Duration::from_millis(40), 6),
+        [0, 0, 1, 1, 2, 2]
+    );
+    //Leftover time carries over, and one long step can skip frames
+    animation.advance(Duration::from_millis(160));
+    assert_eq!(animation.frame_index(), 0);
+}
+
+#[test]
+fn ping_pong_turns_around_at_both_ends() {
+    let mut animation = Animation::uniform(clips(3), FRAME_TIME, Playback::PingPong);
+    let finished = counter(&mut animation);
+    assert_eq!(
+        indices(&mut animation, FRAME_TIME, 8),
+        [1, 2, 1, 0, 1, 2, 1, 0]
+    );
+    assert_eq!(finished.get(), 2);
+}
+
+#[test]
+fn once_stops_on_the_last_frame() {
+    let mut animation = Animation::uniform(clips(3), FRAME_TIME, Playback::Once);
+    let finished = counter(&mut animation);
+    assert_eq!(indices(&mut animation, FRAME_TIME, 2), [1, 2]);
+    assert!(!animation.is_finished());
+
+    //The last frame still gets its full time
+    animation.advance(FRAME_TIME / 2);
+    assert!(!animation.is_finished());
+    animation.advance(Duration::from_secs(5));
+    assert!(animation.is_finished());
+    assert_eq!(animation.frame_index(), 2);
+    assert_eq!(finished.get(), 1);
+
+    animation.advance(FRAME_TIME);
+    assert_eq!(finished.get(), 1);
+
+    animation.restart();
+    assert!(!animation.is_finished());
+    assert_eq!(animation.frame_index(), 0);
+}
+
+#[test]
+fn loop_calls_back_on_every_wrap() {
+    let mut animation = Animation::uniform(clips(2), FRAME_TIME, Playback::Loop);
+    let finished = counter(&mut animation);
+    animation.advance(FRAME_TIME * 7);
+    assert_eq!(finished.get(), 3);
+    assert_eq!(animation.frame_index(), 1);
+}
+
+#[test]
+fn frames_can_have_their_own_durations() {
+    let frames = [
+        AnimationFrame {
+            clip: Rect::new(0, 0, 8, 8),
+            duration: Duration::from_millis(50),
+        },
+        AnimationFrame {
+            clip: Rect::new(8, 0, 8, 8),
+            duration: Duration::from_millis(200),
+        },
+    ];
+    let mut animation = Animation::new(frames, Playback::Loop);
+    assert_eq!(
+        indices(&mut animation, Duration::from_millis(50), 6),
+        [1, 1, 1, 1, 0, 1]
+    );
+}
+
+#[test]
+fn time_scale_sets_independent_speeds() {
+    let mut normal = Animation::uniform(clips(4), FRAME_TIME, Playback::Loop);
+    let mut fast = Animation::uniform(clips(4), FRAME_TIME, Playback::Loop);
+    fast.set_time_scale(2.0);
+    let mut frozen = Animation::uniform(clips(4), FRAME_TIME, Playback::Loop);
+    frozen.set_time_scale(-1.0);
+    assert_eq!(frozen.time_scale(), 0.0);
+
+    for animation in [&mut normal, &mut fast, &mut frozen] {
+        animation.advance(Duration::from_millis(150));
+    }
+    assert_eq!(normal.frame_index(), 1);
+    assert_eq!(fast.frame_index(), 3);
+    assert_eq!(frozen.frame_index(), 0);
+}
+
+#[test]
+fn zero_length_loop_does_not_hang() {
+    let mut animation = Animation::uniform(clips(3), Duration::ZERO, Playback::Loop);
+    animation.advance(FRAME_TIME);
+    assert_eq!(animation.frame_index(), 0);
+
+    let mut once = Animation::uniform(clips(3), Duration::ZERO, Playback::Once);
+    once.advance(FRAME_TIME);
+    assert!(once.is_finished());
+}
@@ -0,0 +1,285 @@
+//! Loads real resources through `AssetCache` on SDL's dummy video driver to
+//! check sharing, memory accounting, unloading and reloading.
+
+mod common;
+
+use common::{cases, expect, Failure, Outcome};
+use lazyfoo::assets::AssetStats;
+use lazyfoo::audio::open_audio;
+use lazyfoo::texture::DEFAULT_COLOR_KEY;
+use lazyfoo::{AssetCache, Error, Reload, TextureHandle};
+use sdl2::pixels::Color;
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+const DOT: &str = "resources/dot.bmp";
+const FONT: &str = "resources/lazy.ttf";
+
+fn stats_are(assets: &AssetCache, textures: usize, fonts: usize, chunks: usize) -> Outcome {
+    let stats = assets.stats();
+    expect(
+        (stats.textures, stats.fonts, stats.chunks) == (textures, fonts, chunks),
+        &format!("holding {stats:?}, expected {textures} textures, {fonts} fonts, {chunks} chunks"),
+    )
+}
+
+fn loading_twice_shares_one_texture(assets: &AssetCache) -> Outcome {
+    let first = assets.load_texture(DOT)?;
+    let second = assets.load_texture(format!("./{DOT}"))?;
+    stats_are(assets, 1, 0, 0)?;
+    expect(
+        assets.handle_count(&first) == 2,
+        "both loads should count as handles",
+    )?;
+    drop(second);
+    expect(
+        assets.handle_count(&first) == 1,
+        "dropping a handle should release it",
+    )
+}
+
+fn color_keys_load_separately(assets: &AssetCache) -> Outcome {
+    let keyed = assets.load_texture(DOT)?;
+    let plain = assets.load_texture_with_color_key(DOT, None)?;
+    let same = assets.load_texture_with_color_key(DOT, Some(DEFAULT_COLOR_KEY))?;
+    let other = assets.load_texture_with_color_key(DOT, Some(Color::RGB(0, 0, 0)))?;
+    stats_are(assets, 3, 0, 0)?;
+    expect(
+        assets.handle_count(&keyed) == 2,
+        "the default key was loaded twice",
+    )?;
+    drop((plain, same, other));
+    Ok(())
+}
+
+fn fonts_are_keyed_by_size(assets: &AssetCache) -> Outcome {
+    let small = assets.load_font(FONT, 18)?;
+    let _large = assets.load_font(FONT, 28)?;
+    let _small_again = assets.load_font(FONT, 18)?;
+    stats_are(assets, 0, 2, 0)?;
+    let height = assets.font(&small)?.height();
+    expect(height > 0, "the font should be usable")
+}
+
+fn chunks_are_shared(assets: &AssetCache) -> Outcome {
+    let high = assets.load_chunk("resources/high.wav")?;
+    let _again = assets.load_chunk("resources/high.wav")?;
+    let _low = assets.load_chunk("resources/low.wav")?;
+    stats_are(assets, 0, 0, 2)?;
+    expect(assets.handle_count(&high) == 2, "high.wav was loaded twice")
+}
+
+//Textures count their pixels, fonts and chunks the size of their files
+fn tracks_memory(assets: &AssetCache) -> Outcome {
+    let dot = assets.load_texture(DOT)?;
+    let texture_bytes = {
+        let texture = assets.texture(&dot)?;
+        (texture.width() * texture.height()) as usize * texture.format().byte_size_per_pixel()
+    };
+    expect(texture_bytes >= 20 * 20 * 3, "dot.bmp is 20x20")?;
+    expect(
+        assets.stats().bytes == texture_bytes,
+        "only the dot is loaded",
+    )?;
+
+    let _font = assets.load_font(FONT, 20)?;
+    let font_bytes = std::fs::metadata(FONT)?.len() as usize;
+    expect(
+        assets.stats().bytes == texture_bytes + font_bytes,
+        "the font should add its file size",
+    )
+}
+
+fn unused_assets_can_be_dropped(assets: &AssetCache) -> Outcome {
+    let kept = assets.load_texture(DOT)?;
+    drop(assets.load_texture("resources/arrow.png")?);
+    drop(assets.load_font(FONT, 12)?);
+    stats_are(assets, 2, 1, 0)?;
+
+    expect(assets.unload_unused() == 2, "two assets had no handles")?;
+    stats_are(assets, 1, 0, 0)?;
+    expect(
+        assets.texture(&kept).is_ok(),
+        "a held texture should stay loaded",
+    )
+}
+
+fn unloading_invalidates_handles(assets: &AssetCache) -> Outcome {
+    let stale = assets.load_texture(DOT)?;
+    let copy = stale.clone();
+    assets.unload(&stale);
+    stats_are(assets, 0, 0, 0)?;
+    expect(
+        matches!(assets.texture(&copy), Err(Error::Unloaded { .. })),
+        "every handle to an unloaded texture should fail",
+    )?;
+    expect(
+        assets.handle_count(&stale) == 0,
+        "unloaded assets have no handles",
+    )?;
+
+    //Loading again gives a fresh asset that the old handles don't reach
+    let fresh = assets.load_texture(DOT)?;
+    expect(assets.texture(&fresh).is_ok(), "the reload should work")?;
+    expect(
+        assets.texture(&stale).is_err(),
+        "old handles should stay stale",
+    )?;
+    assets.unload(&stale);
+    stats_are(assets, 1, 0, 0)
+}
+
+fn missing_files_are_not_cached(assets: &AssetCache) -> Outcome {
+    expect(
+        matches!(
+            assets.load_texture("resources/missing.png"),
+            Err(Error::AssetNotFound { .. })
+        ),
+        "a missing image should be reported",
+    )?;
+    stats_are(assets, 0, 0, 0)
+}
+
+//Handles carry no lifetime, so plain structs can hold them
+struct Sprite {
+    texture: TextureHandle,
+}
+
+fn handles_outlive_their_loader(assets: &AssetCache) -> Outcome {
+    let sprite = {
+        let loader = assets.clone();
+        Sprite {
+            texture: loader.load_texture(DOT)?,
+        }
+    };
+    let width = assets.texture(&sprite.texture)?.width();
+    expect(width == 20, "the shared cache should still hold the dot")
+}
+
+//Writes a scratch copy of a file. Each version gets its own modification
+//time, so rewrites show up even where timestamps are coarse.
+fn write_scratch(name: &str, contents: &[u8], version: u64) -> io::Result<PathBuf> {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents)?;
+    File::options().write(true).open(&path).and_then(|file| {
+        file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 + version))
+    })?;
+    Ok(path)
+}
+
+fn changed_files_reload_in_place(assets: &AssetCache) -> Outcome {
+    let path = write_scratch("changed.bmp", &fs::read(DOT)?, 0)?;
+    let handle = assets.load_texture(&path)?;
+    let copy = handle.clone();
+    expect(assets.reload_changed().is_empty(), "nothing changed yet")?;
+    let dot_bytes = assets.stats().bytes;
+
+    write_scratch("changed.bmp", &fs::read("resources/up.bmp")?, 1)?;
+    let reloads = assets.reload_changed();
+    expect(reloads.len() == 1, "the rewritten file should reload")?;
+    expect(reloads[0].path == path, "the reload should name the file")?;
+    expect(reloads[0].result.is_ok(), "the new image is valid")?;
+
+    let width = assets.texture(&copy)?.width();
+    expect(width != 20, "existing handles should see the new image")?;
+    expect(assets.handle_count(&handle) == 2, "handles should survive")?;
+    expect(assets.generation(&handle) == 1, "one reload happened")?;
+    expect(
+        assets.stats().bytes > dot_bytes,
+        "memory should follow the image",
+    )?;
+    expect(assets.reload_changed().is_empty(), "only changes reload")
+}
+
+fn broken_files_keep_the_old_asset(assets: &AssetCache) -> Outcome {
+    let path = write_scratch("broken.bmp", &fs::read(DOT)?, 0)?;
+    let handle = assets.load_texture(&path)?;
+
+    write_scratch("broken.bmp", b"not an image", 1)?;
+    let reloads = assets.reload_changed();
+    expect(
+        matches!(
+            reloads[..],
+            [Reload {
+                result: Err(Error::Decode { .. }),
+                ..
+            }]
+        ),
+        "a file that doesn't decode should be reported",
+    )?;
+    let width = assets.texture(&handle)?.width();
+    expect(width == 20, "the old image should stay loaded")?;
+    expect(assets.generation(&handle) == 0, "nothing was reloaded")?;
+    expect(
+        assets.reload_changed().is_empty(),
+        "a bad file is tried once",
+    )?;
+
+    //Fixing the file picks it up again
+    write_scratch("broken.bmp", &fs::read("resources/up.bmp")?, 2)?;
+    expect(
+        assets.reload_changed().len() == 1,
+        "the fixed file should reload",
+    )?;
+    expect(assets.generation(&handle) == 1, "the fix was reloaded")
+}
+
+fn missing_files_wait_to_reload(assets: &AssetCache) -> Outcome {
+    let path = write_scratch("missing.ttf", &fs::read(FONT)?, 0)?;
+    let handle = assets.load_font(&path, 16)?;
+    fs::remove_file(&path)?;
+    expect(
+        assets.reload_changed().is_empty(),
+        "a deleted file is skipped",
+    )?;
+    expect(assets.font(&handle).is_ok(), "the font should stay loaded")?;
+
+    write_scratch("missing.ttf", &fs::read(FONT)?, 1)?;
+    expect(
+        assets.reload_changed().len() == 1,
+        "a restored file reloads",
+    )?;
+    expect(assets.font(&handle).is_ok(), "the font should still work")
+}
+
+type Case = fn(&AssetCache) -> Outcome;
+
+fn main() -> ExitCode {
+    let sdl = common::Sdl::start("assets");
+    let texture_creator = sdl.canvas.texture_creator();
+
+    //Chunks need a mixer, which headless machines may not have
+    let audio = open_audio();
+
+    let cases: [(&str, Case); 12] = cases![
+        loading_twice_shares_one_texture,
+        color_keys_load_separately,
+        fonts_are_keyed_by_size,
+        chunks_are_shared,
+        tracks_memory,
+        unused_assets_can_be_dropped,
+        unloading_invalidates_handles,
+        missing_files_are_not_cached,
+        handles_outlive_their_loader,
+        changed_files_reload_in_place,
+        broken_files_keep_the_old_asset,
+        missing_files_wait_to_reload,
+    ];
+    common::run("assets", &cases, |name, case| {
+        if let (true, Err(e)) = (name == "chunks_are_shared", &audio) {
+            return Err(Failure::Skipped(e.to_string()));
+        }
+
+        //Every case starts from an empty cache and must leave nothing unused
+        let assets = AssetCache::new(&texture_creator, &sdl.ttf);
+        case(&assets)?;
+        assets.unload_unused();
+        expect(
+            assets.stats() == AssetStats::default(),
+            "assets were left behind",
+        )
+    })
+}
@@ -0,0 +1,94 @@
+use lazyfoo::atlas::{pack, Packing};
+use sdl2::rect::Rect;
+
+//Sizes of the lesson images that usually end up together
+const RESOURCES: [(u32, u32); 5] = [
+    (20, 20),   //dot.bmp
+    (150, 100), //arrow.png
+    (300, 800), //button.png
+    (64, 205),  //foo.png
+    (256, 205), //foo2.png
+];
+
+fn check(sizes: &[(u32, u32)], page_size: (u32, u32), padding: u32, packing: &Packing) {
+    assert_eq!(packing.regions.len(), sizes.len());
+    let padded = |rect: Rect| {
+        Rect::new(
+            rect.x() - padding as i32,
+            rect.y() - padding as i32,
+            rect.width() + padding * 2,
+            rect.height() + padding * 2,
+        )
+    };
+
+    for (i, (region, &(width, height))) in packing.regions.iter().zip(sizes).enumerate() {
+        assert_eq!(region.rect.size(), (width, height), "image {i} was resized");
+        let (page_width, page_height) = packing.page_sizes[region.page];
+        assert!(page_width <= page_size.0 && page_height <= page_size.1);
+        //Padding is kept on every side, page edges included
+        let outer = padded(region.rect);
+        assert!(
+            outer.x() >= 0 && outer.y() >= 0,
+            "image {i} touches the edge"
+        );
+        assert!(outer.right() as u32 <= page_width && outer.bottom() as u32 <= page_height);
+
+        for (j, other) in packing.regions.iter().enumerate().skip(i + 1) {
+            if other.page == region.page {
+                assert!(
+                    !region.rect.has_intersection(padded(other.rect)),
+                    "images {i} and {j} are closer than the padding"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn fits_the_lesson_images_on_one_page() {
+    let packing = pack(&RESOURCES, (1024, 1024), 1).unwrap();
+    check(&RESOURCES, (1024, 1024), 1, &packing);
+    assert_eq!(packing.page_sizes.len(), 1);
+}
+
+#[test]
+fn opens_more_pages_when_full() {
+    let packing = pack(&RESOURCES, (310, 820), 2).unwrap();
+    check(&RESOURCES, (310, 820), 2, &packing);
+    assert!(packing.page_sizes.len() > 1);
+    //button.png fills a page on its own
+    let button = packing.regions[2].page;
+    assert!(packing
+        .regions
+        .iter()
+        .enumerate()
+        .all(|(i, region)| i == 2 || region.page != button));
+}
+
+#[test]
+fn packs_many_mixed_sizes() {
+    let sizes: Vec<_> = (0..200u32)
+        .map(|i| (1 + (i * 37) % 90, 1 + (i * 53) % 70))
+        .collect();
+    for padding in [0, 1, 4] {
+        let packing = pack(&sizes, (256, 256), padding).unwrap();
+        check(&sizes, (256, 256), padding, &packing);
+    }
+}
+
+#[test]
+fn reports_images_too_big_for_a_page() {
+    assert_eq!(pack(&RESOURCES, (512, 512), 1), Err(2));
+    //Padding counts against the page size
+    assert_eq!(
+        pack(&[(64, 64)], (64, 64), 0).map(|p| p.page_sizes),
+        Ok(vec![(64, 64)])
+    );
+    assert_eq!(pack(&[(64, 64)], (64, 64), 1), Err(0));
+}
+
+#[test]
+fn packs_nothing_into_no_pages() {
+    let packing = pack(&[], (64, 64), 1).unwrap();
+    assert!(packing.regions.is_empty() && packing.page_sizes.is_empty());
+}
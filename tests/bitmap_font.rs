@@ -0,0 +1,88 @@
+use lazyfoo::bitmap_font::{Glyphs, GRID_SIZE};
+use lazyfoo::pixels::Pixels;
+use sdl2::image::LoadSurface;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::surface::Surface;
+
+const INK: Color = Color::RGBA(255, 255, 255, 255);
+
+//A sheet with 4x4 cells: 'A' is two pixels wide on rows 1-2, 'B' one pixel
+//on row 3, and 'i' one pixel on row 2
+fn sheet() -> Pixels {
+    let mut pixels = Pixels::new(GRID_SIZE * 4, GRID_SIZE * 4, Color::RGBA(0, 0, 0, 255));
+    let mut ink = |c: char, x: i32, y: i32| {
+        let cell = c as i32;
+        pixels.set(cell % 16 * 4 + x, cell / 16 * 4 + y, INK);
+    };
+    ink('A', 1, 1);
+    ink('A', 2, 2);
+    ink('B', 0, 3);
+    ink('i', 3, 2);
+    pixels
+}
+
+#[test]
+fn crops_glyphs_to_their_columns_and_a_shared_height() {
+    let glyphs = Glyphs::detect(&sheet()).unwrap();
+    assert_eq!(glyphs.line_height(), 3);
+    assert_eq!(glyphs.glyph('A'), Some(Rect::new(4 + 1, 16 + 1, 2, 3)));
+    assert_eq!(glyphs.glyph('B'), Some(Rect::new(8, 17, 1, 3)));
+    assert_eq!(glyphs.glyph('i'), Some(Rect::new(36 + 3, 24 + 1, 1, 3)));
+    assert_eq!(glyphs.glyph('C'), None);
+    assert_eq!(glyphs.glyph('é'), None);
+    assert_eq!(glyphs.glyph('\u{263a}'), None);
+}
+
+#[test]
+fn empty_sheets_have_no_glyphs() {
+    let blank = Pixels::new(64, 64, Color::RGBA(0, 0, 0, 255));
+    assert!(Glyphs::detect(&blank).is_none());
+    assert!(Glyphs::detect(&Pixels::new(0, 0, INK)).is_none());
+}
+
+#[test]
+fn lays_out_lines_with_spacing() {
+    let glyphs = Glyphs::detect(&sheet())
+        .unwrap()
+        .with_spacing(1)
+        .with_line_spacing(2);
+    let a = glyphs.glyph('A').unwrap();
+    let b = glyphs.glyph('B').unwrap();
+
+    //Spaces and unknown characters are half a cell wide
+    assert_eq!(
+        glyphs.layout("AB A\nC B"),
+        [
+            (a, Point::new(0, 0)),
+            (b, Point::new(3, 0)),
+            (a, Point::new(8, 0)),
+            (b, Point::new(6, 5)),
+        ]
+    );
+    assert_eq!(glyphs.measure("AB A\nC B"), (10, 8));
+    assert_eq!(glyphs.measure(""), (0, 3));
+    assert_eq!(glyphs.measure("B\n"), (1, 8));
+}
+
+#[test]
+fn kerning_moves_pairs_only() {
+    let glyphs = Glyphs::detect(&sheet())
+        .unwrap()
+        .with_kerning([(('A', 'B'), -1), (('B', 'A'), 2)]);
+    let positions: Vec<_> = glyphs
+        .layout("ABA\nBi")
+        .iter()
+        .map(|(_, at)| (at.x(), at.y()))
+        .collect();
+    assert_eq!(positions, [(0, 0), (1, 0), (4, 0), (0, 3), (1, 3)]);
+}
+
+#[test]
+fn finds_every_glyph_of_the_lesson_sheet() {
+    let surface = Surface::from_file("resources/lazyfont.png").unwrap();
+    let glyphs = Glyphs::detect(&Pixels::from_surface(&surface).unwrap()).unwrap();
+    assert!(('!'..='~').all(|c| glyphs.glyph(c).is_some()));
+    assert_eq!(glyphs.glyph(' '), None);
+    assert!(glyphs.glyph('i').unwrap().width() < glyphs.glyph('W').unwrap().width());
+}
@@ -0,0 +1,137 @@
+//! Captures lessons headlessly on SDL's dummy video driver and checks what
+//! was written.
+
+mod common;
+
+use common::{cases, expect, Outcome};
+use lazyfoo::capture::{self, CaptureFormat, FrameCapture, Snapshot};
+use lazyfoo::harness::{render_lesson, run_headless, Script};
+use lazyfoo::lessons::{geometry_rendering, motion};
+use lazyfoo::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+//A fresh directory for one case's output
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("capture")
+        .join(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+fn captures_a_range_of_frames_as_pngs() -> Outcome {
+    let dir = scratch_dir("pngs")?;
+    let mut frames = FrameCapture::new(&dir, 2..5);
+    expect(
+        frames.format() == CaptureFormat::PngSequence,
+        "paths without .gif are directories",
+    )?;
+    run_headless(&motion::LESSON, 8, &Script::new(), |_, canvas| {
+        frames.frame(canvas)
+    })?;
+    expect(frames.is_finished(), "every frame in range went by")?;
+    expect(frames.finish()? == 3, "three frames")?;
+
+    let mut names: Vec<_> = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<io::Result<_>>()?;
+    names.sort();
+    expect(
+        names == ["frame-0002.png", "frame-0003.png", "frame-0004.png"],
+        &format!("files are numbered by frame, got {names:?}"),
+    )?;
+
+    let last = Snapshot::load_png(dir.join("frame-0004.png"))?;
+    let rendered = render_lesson(&motion::LESSON, 5, &Script::new())?;
+    expect(last == rendered, "a captured frame is what the lesson drew")
+}
+
+fn captures_frames_into_one_gif() -> Outcome {
+    let path = scratch_dir("gif")?.join("motion.gif");
+    let mut frames = FrameCapture::new(&path, 0..4).with_frame_delay(Duration::from_millis(50));
+    expect(
+        frames.format() == CaptureFormat::Gif,
+        "paths ending in .gif",
+    )?;
+    run_headless(&motion::LESSON, 10, &Script::new(), |_, canvas| {
+        frames.frame(canvas)
+    })?;
+    expect(frames.finish()? == 4, "four frames")?;
+
+    let file = File::open(&path)?;
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(file)?;
+    expect(
+        (decoder.width() as u32, decoder.height() as u32) == (SCREEN_WIDTH, SCREEN_HEIGHT),
+        "the GIF is the size of the window",
+    )?;
+    let mut delays = Vec::new();
+    while let Some(frame) = decoder.read_next_frame()? {
+        delays.push(frame.delay);
+    }
+    expect(
+        delays == [5, 5, 5, 5],
+        &format!("four frames 50ms apart, got delays {delays:?}"),
+    )
+}
+
+fn short_delays_play_at_fifty_frames_a_second() -> Outcome {
+    let path = scratch_dir("fast")?.join("fast.gif");
+    let mut frames = FrameCapture::new(&path, 0..1);
+    run_headless(
+        &geometry_rendering::LESSON,
+        1,
+        &Script::new(),
+        |_, canvas| frames.frame(canvas),
+    )?;
+    frames.finish()?;
+
+    let file = File::open(&path)?;
+    let mut decoder = gif::DecodeOptions::new().read_info(file)?;
+    let frame = decoder.read_next_frame()?.ok_or("the GIF has a frame")?;
+    expect(frame.delay == 2, "a 60 fps tick is shown for 2 hundredths")
+}
+
+fn screenshots_are_numbered() -> Outcome {
+    let dir = scratch_dir("screenshots")?;
+    let mut saved = Vec::new();
+    run_headless(
+        &geometry_rendering::LESSON,
+        2,
+        &Script::new(),
+        |_, canvas| {
+            saved.push(capture::screenshot(canvas, &dir, "geometry")?);
+            Ok(())
+        },
+    )?;
+    expect(
+        saved == [dir.join("geometry-001.png"), dir.join("geometry-002.png")],
+        &format!("got {saved:?}"),
+    )?;
+    let shot = Snapshot::load_png(&saved[1])?;
+    expect(
+        (shot.width, shot.height) == (SCREEN_WIDTH, SCREEN_HEIGHT),
+        "screenshots are the size of the window",
+    )
+}
+
+type Case = fn() -> Outcome;
+
+fn main() -> ExitCode {
+    common::setup();
+
+    let cases: [(&str, Case); 4] = cases![
+        captures_a_range_of_frames_as_pngs,
+        captures_frames_into_one_gif,
+        short_delays_play_at_fifty_frames_a_second,
+        screenshots_are_numbered,
+    ];
+    common::run("capture", &cases, |_, case| case())
+}
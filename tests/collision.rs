@@ -0,0 +1,501 @@
+use lazyfoo::collision::{contact, intersects, sweep, Circle, Polygon, Shape};
+use lazyfoo::harness::Script;
+use lazyfoo::input::DEFAULT_BINDINGS;
+use lazyfoo::{Bindings, Dot, Input};
+use proptest::prelude::*;
+use sdl2::keyboard::Keycode;
+use sdl2::rect::{FPoint, Point, Rect};
+use std::slice;
+use std::time::Duration;
+
+//Shapes are drawn from this area, so the oracle has few pixels to check
+const AREA: i32 = 24;
+
+//Brute-force pixel oracle: a shape covers a pixel if it covers its center,
+//and comes near it if the center is less than a pixel away
+fn covers(shape: &Shape, x: f64, y: f64) -> bool {
+    distance(shape, x, y) < 0.0
+}
+
+fn near(shape: &Shape, x: f64, y: f64) -> bool {
+    distance(shape, x, y) < 1.0
+}
+
+//Distance from (x, y) to the shape, negative inside it. Empty shapes are
+//infinitely far from everything
+fn distance(shape: &Shape, x: f64, y: f64) -> f64 {
+    match shape {
+        Shape::Aabb(rect) => rect_distance(*rect, x, y),
+        Shape::Circle(c) if c.r <= 0 => f64::INFINITY,
+        Shape::Circle(c) => (x - c.x as f64).hypot(y - c.y as f64) - c.r as f64,
+        Shape::Boxes(boxes) => boxes
+            .iter()
+            .map(|rect| rect_distance(*rect, x, y))
+            .fold(f64::INFINITY, f64::min),
+        Shape::Polygon(polygon) => polygon_distance(polygon.points(), x, y),
+    }
+}
+
+fn rect_distance(rect: Rect, x: f64, y: f64) -> f64 {
+    let (left, right) = (rect.left() as f64, rect.right() as f64);
+    let (top, bottom) = (rect.top() as f64, rect.bottom() as f64);
+    let dx = (left - x).max(x - right);
+    let dy = (top - y).max(y - bottom);
+    if dx < 0.0 && dy < 0.0 {
+        dx.max(dy)
+    } else {
+        dx.max(0.0).hypot(dy.max(0.0))
+    }
+}
+
+fn polygon_distance(points: &[Point], x: f64, y: f64) -> f64 {
+    let area: f64 = edges(points).map(|(a, b)| a.0 * b.1 - a.1 * b.0).sum();
+    let mut inside = true;
+    let mut nearest = f64::INFINITY;
+    for (a, b) in edges(points) {
+        let (ex, ey) = (b.0 - a.0, b.1 - a.1);
+        let (px, py) = (x - a.0, y - a.1);
+        if (ex * py - ey * px) * area.signum() <= 0.0 {
+            inside = false;
+        }
+        let t = ((px * ex + py * ey) / (ex * ex + ey * ey)).clamp(0.0, 1.0);
+        nearest = nearest.min((px - t * ex).hypot(py - t * ey));
+    }
+    if inside {
+        -nearest
+    } else {
+        nearest
+    }
+}
+
+fn edges(points: &[Point]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    let corner = |p: &Point| (p.x() as f64, p.y() as f64);
+    points
+        .iter()
+        .map(corner)
+        .zip(points.iter().cycle().skip(1).map(corner))
+}
+
+//Pixel centers around both shapes
+fn pixels(a: &Shape, b: &Shape) -> impl Iterator<Item = (f64, f64)> {
+    let bounds = [a, b]
+        .iter()
+        .filter_map(|shape| shape.bounding_box())
+        .reduce(|a, b| a.union(b))
+        .unwrap_or_else(|| Rect::new(0, 0, 1, 1));
+    let (left, top) = (bounds.left() - 2, bounds.top() - 2);
+    let (right, bottom) = (bounds.right() + 2, bounds.bottom() + 2);
+    (top..bottom).flat_map(move |y| (left..right).map(move |x| (x as f64 + 0.5, y as f64 + 0.5)))
+}
+
+fn oracle_overlaps(a: &Shape, b: &Shape) -> bool {
+    pixels(a, b).any(|(x, y)| covers(a, x, y) && covers(b, x, y))
+}
+
+fn oracle_near(a: &Shape, b: &Shape) -> bool {
+    pixels(a, b).any(|(x, y)| near(a, x, y) && near(b, x, y))
+}
+
+fn rect() -> impl Strategy<Value = Rect> {
+    (-AREA..AREA, -AREA..AREA, 1..AREA as u32, 1..AREA as u32)
+        .prop_map(|(x, y, w, h)| Rect::new(x, y, w, h))
+}
+
+fn circle() -> impl Strategy<Value = Circle> {
+    (-AREA..AREA, -AREA..AREA, 0..AREA / 2).prop_map(|(x, y, r)| Circle::new(x, y, r))
+}
+
+//The convex hull of a few points, when it has any area
+fn polygon() -> impl Strategy<Value = Polygon> {
+    prop::collection::vec((-AREA..AREA, -AREA..AREA), 3..7)
+        .prop_filter_map("points all in a line", |points| {
+            Polygon::new(convex_hull(points.into_iter().map(Point::from).collect()))
+        })
+}
+
+fn convex_hull(mut points: Vec<Point>) -> Vec<Point> {
+    points.sort_by_key(|p| (p.x(), p.y()));
+    points.dedup();
+    let turn = |o: Point, a: Point, b: Point| {
+        (a.x() - o.x()) as i64 * (b.y() - o.y()) as i64
+            - (a.y() - o.y()) as i64 * (b.x() - o.x()) as i64
+    };
+    let mut hull: Vec<Point> = Vec::new();
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2
+                && turn(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+fn shape() -> impl Strategy<Value = Shape> {
+    prop_oneof![
+        rect().prop_map(Shape::Aabb),
+        circle().prop_map(Shape::Circle),
+        prop::collection::vec(rect(), 1..4).prop_map(Shape::Boxes),
+        polygon().prop_map(Shape::Polygon),
+    ]
+}
+
+//Shapes that are a single convex piece
+fn convex() -> impl Strategy<Value = Shape> {
+    prop_oneof![
+        rect().prop_map(Shape::Aabb),
+        circle().prop_map(Shape::Circle),
+        polygon().prop_map(Shape::Polygon),
+    ]
+}
+
+fn boxes() -> impl Strategy<Value = Shape> {
+    prop_oneof![
+        rect().prop_map(Shape::Aabb),
+        prop::collection::vec(rect(), 1..4).prop_map(Shape::Boxes),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn overlapping_pixels_always_intersect(a in shape(), b in shape()) {
+        prop_assert!(!oracle_overlaps(&a, &b) || intersects(&a, &b));
+    }
+
+    #[test]
+    fn intersecting_shapes_share_a_pixel_or_nearly(a in shape(), b in shape()) {
+        prop_assert!(!intersects(&a, &b) || oracle_near(&a, &b));
+    }
+
+    #[test]
+    fn intersection_is_symmetric(a in shape(), b in shape()) {
+        prop_assert_eq!(intersects(&a, &b), intersects(&b, &a));
+    }
+
+    #[test]
+    fn boxes_intersect_exactly_when_they_share_a_pixel(a in boxes(), b in boxes()) {
+        prop_assert_eq!(intersects(&a, &b), oracle_overlaps(&a, &b));
+    }
+
+    #[test]
+    fn moving_both_shapes_changes_nothing(
+        a in shape(),
+        b in shape(),
+        dx in -AREA..AREA,
+        dy in -AREA..AREA,
+    ) {
+        prop_assert_eq!(
+            intersects(&a, &b),
+            intersects(&a.offset(dx, dy), &b.offset(dx, dy))
+        );
+    }
+
+    #[test]
+    fn contacts_are_found_exactly_when_shapes_intersect(a in shape(), b in shape()) {
+        let manifold = contact(&a, &b);
+        prop_assert_eq!(manifold.is_some(), intersects(&a, &b));
+        if let Some(manifold) = manifold {
+            prop_assert!((manifold.normal.x.hypot(manifold.normal.y) - 1.0).abs() < 1e-4);
+            prop_assert!(manifold.depth > 0.0);
+        }
+    }
+
+    #[test]
+    fn moving_out_by_the_translation_separates(a in convex(), b in convex()) {
+        if let Some(manifold) = contact(&a, &b) {
+            let push = manifold.translation();
+            let out = |v: f32| (v.abs() - 1e-3).ceil().max(0.0).copysign(v) as i32;
+            let moved = a.offset(out(push.x), out(push.y));
+            prop_assert!(!intersects(&moved, &b), "{:?} moved to {:?}", manifold, moved);
+        }
+    }
+
+    #[test]
+    fn boxes_move_out_the_shortest_way(a in rect(), b in rect()) {
+        if let Some(manifold) = contact(&Shape::Aabb(a), &Shape::Aabb(b)) {
+            //Out the left or right, or out the top or bottom
+            let across = (a.right() - b.left())
+                .min(b.right() - a.left())
+                .min(a.bottom() - b.top())
+                .min(b.bottom() - a.top());
+            prop_assert_eq!(manifold.depth, across as f32);
+            prop_assert!(manifold.normal.x == 0.0 || manifold.normal.y == 0.0);
+        }
+    }
+
+    #[test]
+    fn sweeps_stop_at_the_first_step_that_hits(
+        a in shape(),
+        b in shape(),
+        dx in -4..=4,
+        dy in -4..=4,
+        steps in 1..12,
+    ) {
+        if intersects(&a, &b) {
+            return Ok(());
+        }
+        let motion = FPoint::new((dx * steps) as f32, (dy * steps) as f32);
+        let impact = sweep(&a, motion, &b);
+        let hits = |step: i32| intersects(&a.offset(dx * step, dy * step), &b);
+        if let Some(first) = (1..=steps).find(|&step| hits(step)) {
+            let time = impact.map(|impact| impact.time);
+            prop_assert!(
+                time.is_some_and(|time| time <= first as f32 / steps as f32 + 1e-5),
+                "hit at step {} of {}, swept to {:?}", first, steps, time
+            );
+        }
+        if let Some(impact) = impact {
+            let before = (0..=steps).filter(|&step| (step as f32 / steps as f32) < impact.time - 1e-5);
+            for step in before {
+                prop_assert!(!hits(step), "hit at step {} of {} before {:?}", step, steps, impact);
+            }
+        }
+    }
+}
+
+#[test]
+fn touching_shapes_do_not_intersect() {
+    let left = Shape::Aabb(Rect::new(0, 0, 10, 10));
+    let right = Shape::Aabb(Rect::new(10, 0, 10, 10));
+    assert!(!intersects(&left, &right));
+    assert!(!intersects(
+        &Shape::Circle(Circle::new(0, 0, 5)),
+        &Shape::Circle(Circle::new(10, 0, 5))
+    ));
+    assert!(!intersects(&Shape::Circle(Circle::new(-5, 5, 5)), &left));
+    assert!(intersects(&Shape::Circle(Circle::new(-4, 5, 5)), &left));
+}
+
+#[test]
+fn circles_below_wide_boxes_are_caught() {
+    //Measuring the box's bottom with its width would put it 100 pixels down
+    //and miss the circle
+    let wall = Shape::Aabb(Rect::new(0, 0, 100, 10));
+    assert!(intersects(&Shape::Circle(Circle::new(50, 15, 8)), &wall));
+    assert!(!intersects(&Shape::Circle(Circle::new(50, 18, 8)), &wall));
+}
+
+#[test]
+fn only_convex_polygons_with_area_are_made() {
+    let points = |corners: &[(i32, i32)]| corners.iter().copied().map(Point::from).collect();
+    assert!(Polygon::new(points(&[(0, 0), (4, 0), (4, 4), (0, 4)])).is_some());
+    assert!(Polygon::new(points(&[(0, 0), (0, 4), (4, 4), (4, 0)])).is_some());
+    assert!(Polygon::new(points(&[(0, 0), (2, 0), (4, 0), (2, 3)])).is_some());
+    assert!(Polygon::new(points(&[(0, 0), (4, 0), (1, 1), (0, 4)])).is_none());
+    assert!(Polygon::new(points(&[(0, 0), (2, 2), (4, 4)])).is_none());
+    //A pentagram turns the same way at every corner
+    let star = points(&[(0, -10), (6, 8), (-9, -3), (9, -3), (-6, 8)]);
+    assert!(Polygon::new(star).is_none());
+}
+
+#[test]
+fn empty_circles_hit_nothing() {
+    let dot = Shape::Circle(Circle::new(5, 5, 0));
+    assert!(!intersects(&dot, &Shape::Aabb(Rect::new(0, 0, 10, 10))));
+    assert_eq!(dot.bounding_box(), None);
+}
+
+#[test]
+fn contacts_point_out_of_the_other_shape() {
+    let wall = Shape::Aabb(Rect::new(10, 0, 10, 40));
+    let manifold = contact(&Shape::Aabb(Rect::new(0, 10, 13, 10)), &wall).unwrap();
+    assert_eq!((manifold.normal.x, manifold.normal.y), (-1.0, 0.0));
+    assert_eq!(manifold.depth, 3.0);
+
+    let a = Shape::Circle(Circle::new(0, 0, 5));
+    let manifold = contact(&a, &Shape::Circle(Circle::new(3, 4, 5))).unwrap();
+    assert!((manifold.normal.x + 0.6).abs() < 1e-6 && (manifold.normal.y + 0.8).abs() < 1e-6);
+    assert!((manifold.depth - 5.0).abs() < 1e-6);
+    //Circles on the same spot are pushed up
+    let manifold = contact(&a, &Shape::Circle(Circle::new(0, 0, 3))).unwrap();
+    assert_eq!(
+        (manifold.normal.x, manifold.normal.y, manifold.depth),
+        (0.0, -1.0, 8.0)
+    );
+}
+
+//Holds `keys` and moves `dot` for `ticks` sixtieths of a second as
+//`collider`, running into `obstacles`
+fn run<F>(mut dot: Dot, keys: &[Keycode], ticks: u32, obstacles: &[Shape], collider: F) -> Dot
+where
+    F: Fn(&Dot) -> Shape,
+{
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS).unwrap());
+    let script = keys
+        .iter()
+        .fold(Script::new(), |script, &key| script.key_down(0, key));
+    for event in script.events_at(0) {
+        input.handle_event(&event);
+    }
+    for _ in 0..ticks {
+        dot.handle_input(&input);
+        dot.move_position_with(
+            Rect::new(0, 0, 640, 480),
+            Duration::from_secs(1) / 60,
+            obstacles,
+            &collider,
+        );
+    }
+    dot
+}
+
+fn box_collider(dot: &Dot) -> Shape {
+    Shape::Aabb(dot.collider())
+}
+
+fn circle_collider(dot: &Dot) -> Shape {
+    let r = Dot::DOT_WIDTH as i32 / 2;
+    let position = dot.collider();
+    Shape::Circle(Circle::new(position.x() + r, position.y() + r, r))
+}
+
+//200 pixels every sixtieth of a second
+const FAST: f32 = 200.0 * 60.0;
+
+#[test]
+fn dots_rest_flush_against_walls() {
+    //Steps of 7 pixels never land exactly on the wall
+    let wall = Shape::Aabb(Rect::new(300, 0, 40, 480));
+    let dot = Dot::new(0.0, 100.0, 420.0);
+    let dot = run(dot, &[Keycode::Right], 60, &[wall], box_collider);
+    assert_eq!(dot.collider().right(), 300);
+}
+
+#[test]
+fn dots_slide_along_walls() {
+    let wall = Shape::Aabb(Rect::new(300, 40, 40, 400));
+    let dot = Dot::new(270.0, 100.0, 240.0);
+    let dot = run(
+        dot,
+        &[Keycode::Right, Keycode::Down],
+        20,
+        &[wall],
+        box_collider,
+    );
+    assert_eq!(dot.collider().right(), 300);
+    assert_eq!(dot.collider().top(), 180);
+}
+
+#[test]
+fn dots_stop_at_the_edge_of_the_screen() {
+    let dot = run(
+        Dot::new(600.0, 0.0, 420.0),
+        &[Keycode::Right],
+        10,
+        &[],
+        box_collider,
+    );
+    assert_eq!(dot.collider().right(), 640);
+}
+
+#[test]
+fn fast_dots_do_not_tunnel_through_thin_walls() {
+    for width in [1, 4, 40] {
+        let wall = Shape::Aabb(Rect::new(310, 0, width, 480));
+        let dot = run(
+            Dot::new(0.0, 100.0, FAST),
+            &[Keycode::Right],
+            3,
+            slice::from_ref(&wall),
+            box_collider,
+        );
+        assert_eq!(dot.collider().right(), 310, "{width}px wall");
+        let dot = run(
+            Dot::new(0.0, 100.0, FAST),
+            &[Keycode::Right],
+            3,
+            &[wall],
+            circle_collider,
+        );
+        assert_eq!(dot.collider().right(), 310, "{width}px wall, round dot");
+    }
+}
+
+#[test]
+fn fast_dots_slide_along_thin_walls() {
+    //Hits the wall a third of the way into the second step, then slides down
+    //for the rest of it
+    let wall = Shape::Aabb(Rect::new(250, 0, 1, 480));
+    let dot = Dot::new(30.0, 0.0, FAST);
+    let dot = run(
+        dot,
+        &[Keycode::Right, Keycode::Down],
+        2,
+        &[wall],
+        box_collider,
+    );
+    assert_eq!((dot.collider().right(), dot.collider().top()), (250, 400));
+}
+
+#[test]
+fn fast_dots_do_not_tunnel_through_small_circles() {
+    let post = Shape::Circle(Circle::new(300, 110, 2));
+    let dot = run(
+        Dot::new(0.0, 100.0, FAST),
+        &[Keycode::Right],
+        3,
+        slice::from_ref(&post),
+        circle_collider,
+    );
+    assert_eq!(dot.collider().right(), 298);
+    let dot = run(
+        Dot::new(0.0, 100.0, FAST),
+        &[Keycode::Right],
+        3,
+        &[post],
+        box_collider,
+    );
+    assert_eq!(dot.collider().right(), 298);
+}
+
+#[test]
+fn swept_boxes_report_when_they_hit() {
+    let dot = Shape::Aabb(Rect::new(0, 0, 20, 20));
+    let wall = Shape::Aabb(Rect::new(100, 0, 2, 20));
+    let impact = sweep(&dot, FPoint::new(200.0, 0.0), &wall).unwrap();
+    assert_eq!(impact.time, 0.4);
+    assert_eq!((impact.normal.x, impact.normal.y), (-1.0, 0.0));
+
+    //Moving away, sliding past, stopping short or starting inside
+    assert_eq!(sweep(&dot, FPoint::new(-200.0, 0.0), &wall), None);
+    assert_eq!(sweep(&dot, FPoint::new(0.0, 200.0), &wall), None);
+    assert_eq!(sweep(&dot, FPoint::new(80.0, 0.0), &wall), None);
+    assert_eq!(
+        sweep(&dot.offset(90, 0), FPoint::new(200.0, 0.0), &wall),
+        None
+    );
+    //Already touching and moving in
+    let impact = sweep(&dot.offset(80, 0), FPoint::new(5.0, 0.0), &wall).unwrap();
+    assert_eq!(impact.time, 0.0);
+}
+
+#[test]
+fn swept_circles_report_when_they_hit() {
+    let dot = Shape::Circle(Circle::new(10, 10, 10));
+    let wall = Shape::Aabb(Rect::new(100, -50, 1, 200));
+    let impact = sweep(&dot, FPoint::new(200.0, 0.0), &wall).unwrap();
+    assert!((impact.time - 0.4).abs() < 1e-6);
+    assert_eq!((impact.normal.x, impact.normal.y), (-1.0, 0.0));
+
+    //Clipping a corner 3 pixels off its path
+    let dot = Shape::Circle(Circle::new(0, 0, 5));
+    let block = Shape::Aabb(Rect::new(50, 3, 10, 10));
+    let impact = sweep(&dot, FPoint::new(100.0, 0.0), &block).unwrap();
+    assert!((impact.time - 0.46).abs() < 1e-6);
+    assert!((impact.normal.x + 0.8).abs() < 1e-6 && (impact.normal.y + 0.6).abs() < 1e-6);
+
+    let other = Shape::Circle(Circle::new(50, 0, 5));
+    let impact = sweep(&dot, FPoint::new(100.0, 0.0), &other).unwrap();
+    assert!((impact.time - 0.4).abs() < 1e-6);
+    assert_eq!(
+        sweep(&dot, FPoint::new(100.0, 0.0), &other.offset(0, 10)),
+        None
+    );
+}
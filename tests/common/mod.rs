@@ -0,0 +1,117 @@
+//! Shared by the tests that drive SDL. SDL must stay on one thread, so those
+//! tests run with their own `main` instead of libtest: they list their cases
+//! and hand them to [`run`], which reports them the way libtest would.
+
+//Each test binary uses only some of this
+#![allow(dead_code)]
+
+pub mod counting;
+
+use lazyfoo::app::create_canvas;
+use lazyfoo::font::init_ttf;
+use sdl2::render::WindowCanvas;
+use sdl2::ttf::Sdl2TtfContext;
+use sdl2::VideoSubsystem;
+use std::env;
+use std::fmt::Display;
+use std::process::ExitCode;
+
+/// Why a case didn't pass.
+pub enum Failure {
+    Failed(String),
+    /// Something the case needs, like an audio device, isn't available.
+    Skipped(String),
+}
+
+//Lets cases use `?` on anything that can be shown
+impl<E: Display> From<E> for Failure {
+    fn from(e: E) -> Self {
+        Failure::Failed(e.to_string())
+    }
+}
+
+pub type Outcome = Result<(), Failure>;
+
+pub fn expect(condition: bool, message: &str) -> Outcome {
+    if condition {
+        Ok(())
+    } else {
+        Err(Failure::Failed(message.to_string()))
+    }
+}
+
+/// Moves to the crate root, which resources are loaded relative to, and
+/// points SDL at its dummy video and audio drivers.
+pub fn setup() {
+    env::set_current_dir(env!("CARGO_MANIFEST_DIR")).expect("crate root is accessible");
+    sdl2::hint::set("SDL_VIDEODRIVER", "dummy");
+    sdl2::hint::set("SDL_AUDIODRIVER", "dummy");
+}
+
+/// SDL started without a visible window, with a software canvas the size of
+/// a lesson's.
+pub struct Sdl {
+    pub context: sdl2::Sdl,
+    pub video: VideoSubsystem,
+    pub canvas: WindowCanvas,
+    pub ttf: Sdl2TtfContext,
+}
+
+impl Sdl {
+    pub fn start(title: &str) -> Self {
+        setup();
+        let context = sdl2::init().expect("SDL starts");
+        let video = context.video().expect("video starts");
+        let canvas = create_canvas(&video, title, true).expect("canvas starts");
+        let ttf = init_ttf().expect("ttf starts");
+        Self {
+            context,
+            video,
+            canvas,
+            ttf,
+        }
+    }
+}
+
+/// Names each case function after itself, for [`run`].
+#[allow(unused_macros)]
+macro_rules! cases {
+    ($($case:ident),* $(,)?) => {
+        [$((stringify!($case), $case as _)),*]
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use cases;
+
+/// Runs every case through `each`, printing a line for each one and a
+/// summary, and fails if any case did.
+pub fn run<C>(
+    suite: &str,
+    cases: &[(&str, C)],
+    mut each: impl FnMut(&str, &C) -> Outcome,
+) -> ExitCode {
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for (name, case) in cases {
+        match each(name, case) {
+            Ok(()) => {
+                println!("{suite} {name} ... ok");
+                passed += 1;
+            }
+            Err(Failure::Skipped(reason)) => {
+                println!("{suite} {name} ... skipped ({reason})");
+                skipped += 1;
+            }
+            Err(Failure::Failed(reason)) => {
+                println!("{suite} {name} ... FAILED ({reason})");
+                failed += 1;
+            }
+        }
+    }
+
+    println!("\n{suite} result: {passed} passed; {failed} failed; {skipped} skipped");
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
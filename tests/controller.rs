@@ -0,0 +1,187 @@
+//! Drives controller input through SDL's real event queue: synthetic
+//! controller events are pushed, pumped back out, and fed to `Controllers`,
+//! `Input` and a `Dot` the way the lesson runner does.
+
+mod common;
+
+use common::{cases, expect, Outcome};
+use lazyfoo::controller::Controllers;
+use lazyfoo::input::DEFAULT_BINDINGS;
+use lazyfoo::{Bindings, Dot, Input};
+use sdl2::controller::{Axis, Button};
+use sdl2::event::Event;
+use sdl2::rect::Rect;
+use sdl2::{EventPump, EventSubsystem};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const DOT_VEL: f32 = 600.0;
+const STEP: Duration = Duration::from_millis(10);
+//One step at full speed
+const FULL_STEP_PX: f32 = DOT_VEL * 0.01;
+const PAD: u32 = 7;
+
+struct Rig {
+    events: EventSubsystem,
+    pump: EventPump,
+    controllers: Controllers,
+    input: Input,
+}
+
+impl Rig {
+    fn push(&self, event: Event) {
+        self.events.push_event(event).expect("event queue has room");
+    }
+
+    fn axis(&self, axis: Axis, fraction: f32) {
+        self.push(Event::ControllerAxisMotion {
+            timestamp: 0,
+            which: PAD,
+            axis,
+            value: (fraction * i16::MAX as f32) as i16,
+        });
+    }
+
+    //Pumps the queue like one frame of the runner and returns how far a dot moves in one step
+    fn step(&mut self) -> (f32, f32) {
+        for event in self.pump.poll_iter().collect::<Vec<_>>() {
+            //Pushed device indices don't exist, so opening is expected to fail
+            let _ = self.controllers.handle_event(&event);
+            self.input.handle_event(&event);
+        }
+        let mut dot = Dot::new(500.0, 500.0, DOT_VEL);
+        dot.handle_input(&self.input);
+        dot.move_position(Rect::new(0, 0, 1000, 1000), STEP);
+        (dot.x_pos - 500.0, dot.y_pos - 500.0)
+    }
+}
+
+fn close(actual: f32, expected: f32) -> Outcome {
+    expect(
+        (actual - expected).abs() < 0.01,
+        &format!("moved {actual}, expected {expected}"),
+    )
+}
+
+fn full_stick_moves_at_full_speed(rig: &mut Rig) -> Outcome {
+    rig.axis(Axis::LeftX, 1.0);
+    let (dx, dy) = rig.step();
+    close(dx, FULL_STEP_PX)?;
+    close(dy, 0.0)
+}
+
+fn stick_inside_dead_zone_is_ignored(rig: &mut Rig) -> Outcome {
+    rig.axis(Axis::LeftX, 0.15);
+    rig.axis(Axis::LeftY, -0.1);
+    let (dx, dy) = rig.step();
+    close(dx, 0.0)?;
+    close(dy, 0.0)
+}
+
+//Each axis alone is inside the dead zone, but the stick as a whole is not
+fn dead_zone_is_radial(rig: &mut Rig) -> Outcome {
+    rig.axis(Axis::LeftX, 0.18);
+    rig.axis(Axis::LeftY, 0.18);
+    let (dx, dy) = rig.step();
+    expect(
+        dx > 0.0 && dy > 0.0,
+        &format!("moved ({dx}, {dy}) on a diagonal past the dead zone"),
+    )?;
+    close(dx, dy)
+}
+
+//Output is rescaled from the edge of the dead zone: 0.6 travels (0.6 - 0.2) / 0.8
+fn deflection_past_dead_zone_is_rescaled(rig: &mut Rig) -> Outcome {
+    rig.axis(Axis::LeftY, -0.6);
+    let (dx, dy) = rig.step();
+    close(dx, 0.0)?;
+    close(dy, -FULL_STEP_PX * 0.5)
+}
+
+fn dead_zone_is_configurable(rig: &mut Rig) -> Outcome {
+    let mut bindings = rig.input.bindings().clone();
+    bindings.dead_zone = 0.5;
+    rig.input = Input::new(bindings);
+    rig.axis(Axis::LeftX, 0.45);
+    close(rig.step().0, 0.0)?;
+    rig.axis(Axis::LeftX, 0.75);
+    close(rig.step().0, FULL_STEP_PX * 0.5)
+}
+
+fn dpad_moves_at_full_speed(rig: &mut Rig) -> Outcome {
+    rig.push(Event::ControllerButtonDown {
+        timestamp: 0,
+        which: PAD,
+        button: Button::DPadLeft,
+    });
+    close(rig.step().0, -FULL_STEP_PX)?;
+    rig.push(Event::ControllerButtonUp {
+        timestamp: 0,
+        which: PAD,
+        button: Button::DPadLeft,
+    });
+    close(rig.step().0, 0.0)
+}
+
+fn unplugging_releases_the_pad(rig: &mut Rig) -> Outcome {
+    rig.axis(Axis::LeftX, 1.0);
+    rig.push(Event::ControllerButtonDown {
+        timestamp: 0,
+        which: PAD,
+        button: Button::DPadDown,
+    });
+    rig.step();
+    rig.push(Event::ControllerDeviceRemoved {
+        timestamp: 0,
+        which: PAD,
+    });
+    let (dx, dy) = rig.step();
+    close(dx, 0.0)?;
+    close(dy, 0.0)
+}
+
+fn plugging_in_a_missing_device_is_an_error(rig: &mut Rig) -> Outcome {
+    let added = Event::ControllerDeviceAdded {
+        timestamp: 0,
+        which: 99,
+    };
+    expect(
+        rig.controllers.handle_event(&added).is_err(),
+        "opened a device that does not exist",
+    )?;
+    expect(rig.controllers.is_empty(), "a controller was kept open")
+}
+
+type Case = fn(&mut Rig) -> Outcome;
+
+fn main() -> ExitCode {
+    common::setup();
+    let sdl_context = sdl2::init().expect("SDL starts");
+    let game_controller = sdl_context.game_controller().expect("controllers start");
+    let events = sdl_context.event().expect("events start");
+    let pump = sdl_context.event_pump().expect("event pump starts");
+    let bindings = Bindings::load(DEFAULT_BINDINGS).expect("default bindings load");
+    let mut rig = Rig {
+        events,
+        pump,
+        controllers: Controllers::new(game_controller),
+        input: Input::new(bindings.clone()),
+    };
+
+    let cases: [(&str, Case); 8] = cases![
+        full_stick_moves_at_full_speed,
+        stick_inside_dead_zone_is_ignored,
+        dead_zone_is_radial,
+        deflection_past_dead_zone_is_rescaled,
+        dead_zone_is_configurable,
+        dpad_moves_at_full_speed,
+        unplugging_releases_the_pad,
+        plugging_in_a_missing_device_is_an_error,
+    ];
+    common::run("controller", &cases, |_, case| {
+        //Start every case from a fresh queue and input state
+        rig.pump.poll_iter().for_each(drop);
+        rig.input = Input::new(bindings.clone());
+        case(&mut rig)
+    })
+}
@@ -0,0 +1,139 @@
+//! Rasterizes TrueType glyphs into a `GlyphAtlas` on SDL's dummy video
+//! driver, and counts heap allocations while drawing from it.
+
+mod common;
+
+use common::counting::{allocations, Counting};
+use common::{cases, expect, Outcome};
+use lazyfoo::font::load_font;
+use lazyfoo::{GlyphAtlas, Result};
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::ttf::Sdl2TtfContext;
+use sdl2::video::WindowContext;
+use std::fmt::Write;
+use std::process::ExitCode;
+
+const FONT: &str = "resources/lazy.ttf";
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+struct Sdl<'a> {
+    canvas: &'a mut WindowCanvas,
+    texture_creator: &'a TextureCreator<WindowContext>,
+    ttf_context: &'a Sdl2TtfContext,
+}
+
+impl<'a> Sdl<'a> {
+    fn atlas(&self, point_size: u16) -> Result<GlyphAtlas<'a>> {
+        let font = load_font(self.ttf_context, FONT, point_size)?;
+        GlyphAtlas::new(self.texture_creator, font)
+    }
+}
+
+fn rasterizes_each_glyph_once(sdl: &mut Sdl) -> Outcome {
+    let mut atlas = sdl.atlas(32)?;
+    atlas.prepare("0.00")?;
+    expect(atlas.glyph_count() == 2, "'0' and '.' are two glyphs")?;
+    atlas.prepare("000.0")?;
+    expect(atlas.glyph_count() == 2, "known glyphs aren't added again")?;
+    atlas.prepare("12\n3")?;
+    expect(atlas.glyph_count() == 5, "newlines aren't glyphs")
+}
+
+fn measures_like_the_font(sdl: &mut Sdl) -> Outcome {
+    let text = "Average Frames Per Second: 59.94";
+    let font = load_font(sdl.ttf_context, FONT, 32)?;
+    let (font_width, font_height) = font.size_of(text)?;
+
+    let mut atlas = sdl.atlas(32)?;
+    atlas.prepare(text)?;
+    let (width, height) = atlas.measure(text);
+    expect(
+        width.abs_diff(font_width) <= 2,
+        &format!("measured {width} pixels wide, the font says {font_width}"),
+    )?;
+    expect(height == font_height, "one line is as tall as the font")?;
+
+    let (_, two_lines) = atlas.measure("59\n60");
+    expect(two_lines > height, "a second line adds height")?;
+    let fresh = sdl.atlas(32)?;
+    expect(
+        fresh.measure(text) == (width, height),
+        "glyphs are measured before they are prepared",
+    )
+}
+
+fn grows_when_full(sdl: &mut Sdl) -> Outcome {
+    let font = load_font(sdl.ttf_context, FONT, 24)?;
+    let mut atlas = GlyphAtlas::with_size(sdl.texture_creator, font, 64, 16)?;
+    let ascii: String = ('!'..='~').collect();
+    atlas.prepare(&ascii)?;
+    expect(atlas.glyph_count() == ascii.len(), "every glyph fits")?;
+    let (width, height) = atlas.size();
+    expect(width == 64 && height > 16, "the atlas grows taller")?;
+
+    atlas.set_color(0, 0, 0);
+    Ok(atlas.render(sdl.canvas, 0, 0, &ascii)?)
+}
+
+//'W' is too wide for the atlas, so the string fails after '.' was packed
+fn failed_strings_give_back_their_room(sdl: &mut Sdl) -> Outcome {
+    let font = load_font(sdl.ttf_context, FONT, 24)?;
+    let mut atlas = GlyphAtlas::with_size(sdl.texture_creator, font, 16, 16)?;
+    expect(atlas.prepare(".W").is_err(), "'W' doesn't fit")?;
+    //The first try may grow the atlas to fit '.', retrying mustn't
+    let size = atlas.size();
+    for _ in 0..50 {
+        expect(atlas.prepare(".W").is_err(), "'W' still doesn't fit")?;
+    }
+    expect(atlas.glyph_count() == 0, "nothing was added")?;
+    expect(
+        atlas.size() == size,
+        &format!("retries kept packing, the atlas grew to {:?}", atlas.size()),
+    )?;
+    atlas.prepare(".")?;
+    expect(atlas.glyph_count() == 1, "what fits can still be added")
+}
+
+fn drawing_prepared_text_does_not_allocate(sdl: &mut Sdl) -> Outcome {
+    let mut atlas = sdl.atlas(32)?;
+    atlas.prepare("0123456789.")?;
+    atlas.set_color(0, 0, 0);
+    let mut text = String::with_capacity(16);
+
+    let before = allocations();
+    for frame in 0..120 {
+        text.clear();
+        let _ = write!(text, "{:.2}", frame as f32 / 7.0);
+        atlas.prepare(&text)?;
+        let (width, _) = atlas.measure(&text);
+        atlas.render(sdl.canvas, 640 - width as i32, 0, &text)?;
+    }
+    let allocations = allocations() - before;
+    expect(
+        allocations == 0,
+        &format!("{allocations} allocations in 120 frames"),
+    )
+}
+
+type Case = fn(&mut Sdl) -> Outcome;
+
+fn main() -> ExitCode {
+    let mut sdl = common::Sdl::start("glyph atlas");
+    let texture_creator = sdl.canvas.texture_creator();
+    let mut target = Sdl {
+        canvas: &mut sdl.canvas,
+        texture_creator: &texture_creator,
+        ttf_context: &sdl.ttf,
+    };
+
+    let cases: [(&str, Case); 5] = cases![
+        rasterizes_each_glyph_once,
+        measures_like_the_font,
+        grows_when_full,
+        failed_strings_give_back_their_room,
+        drawing_prepared_text_does_not_allocate,
+    ];
+    common::run("glyph_atlas", &cases, |_, case| case(&mut target))
+}
@@ -0,0 +1,237 @@
+//! Renders every lesson headlessly and compares the last frame against
+//! `tests/golden/<lesson>.png`.
+//!
+//! A missing golden fails the case; set `LAZYFOO_BLESS=1` to record new
+//! goldens or re-record all of them after an intended change. Only a bless
+//! run writes to `tests/golden/`. Mismatches leave the actual frame and a
+//! diff under `target/golden-diff/`.
+
+mod common;
+
+use common::{Failure, Outcome};
+use lazyfoo::harness::{render_lesson, Script};
+use lazyfoo::lessons::{self, *};
+use lazyfoo::{Error, LessonInfo};
+use sdl2::keyboard::Keycode;
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+//Lessons without a case: they print wall-clock frame rates
+const EXEMPT: [&str; 2] = ["calculating_frame_rate", "capping_frame_rate"];
+
+//Per-channel difference ignored when comparing pixels
+const CHANNEL_TOLERANCE: u8 = 3;
+//Pixels allowed to differ beyond the channel tolerance
+const MAX_MISMATCHED_PIXELS: usize = 300;
+
+struct Case {
+    lesson: &'static LessonInfo,
+    frames: u32,
+    script: Script,
+}
+
+impl Case {
+    fn new(lesson: &'static LessonInfo, frames: u32, script: Script) -> Self {
+        Self {
+            lesson,
+            frames,
+            script,
+        }
+    }
+
+    fn still(lesson: &'static LessonInfo) -> Self {
+        Self::new(lesson, 1, Script::new())
+    }
+}
+
+fn cases() -> Vec<Case> {
+    vec![
+        Case::still(&open_window::LESSON),
+        Case::still(&surfaces::LESSON),
+        Case::still(&texture::LESSON),
+        Case::new(
+            &key_presses::LESSON,
+            2,
+            Script::new().key_press(0, Keycode::Up, 1),
+        ),
+        Case::still(&geometry_rendering::LESSON),
+        Case::still(&viewport::LESSON),
+        Case::still(&color_keying::LESSON),
+        Case::still(&sprite_sheet::LESSON),
+        Case::new(
+            &color_modulation::LESSON,
+            3,
+            Script::new()
+                .key_press(0, Keycode::A, 1)
+                .key_press(1, Keycode::A, 1)
+                .key_down(2, Keycode::S),
+        ),
+        Case::new(
+            &alpha_blending::LESSON,
+            3,
+            Script::new()
+                .key_press(0, Keycode::S, 1)
+                .key_press(1, Keycode::S, 1)
+                .key_down(2, Keycode::S),
+        ),
+        //Just past 300ms, on the fourth clip
+        Case::new(&animated_sprites::LESSON, 40, Script::new()),
+        Case::new(
+            &rotation_and_flipping::LESSON,
+            2,
+            Script::new()
+                .key_down(0, Keycode::D)
+                .key_down(1, Keycode::Q),
+        ),
+        Case::still(&ttf_fonts::LESSON),
+        Case::new(
+            &mouse_events::LESSON,
+            2,
+            Script::new()
+                .mouse_motion(0, 10, 10)
+                .mouse_button_down(1, 600, 400),
+        ),
+        Case::new(
+            &key_states::LESSON,
+            2,
+            Script::new().key_down(0, Keycode::Left),
+        ),
+        Case::still(&sound_effects::LESSON),
+        Case::new(
+            &timer::LESSON,
+            2,
+            Script::new().key_down(1, Keycode::Return),
+        ),
+        Case::new(
+            &advanced_timer::LESSON,
+            2,
+            Script::new().key_down(0, Keycode::S),
+        ),
+        Case::new(
+            &motion::LESSON,
+            10,
+            Script::new().key_down(0, Keycode::Right),
+        ),
+        Case::new(
+            &box_collision_detection::LESSON,
+            400,
+            Script::new()
+                .key_down(0, Keycode::Right)
+                .key_down(0, Keycode::Down),
+        ),
+        Case::new(
+            &per_pixel_collision_detection::LESSON,
+            200,
+            Script::new()
+                .key_down(0, Keycode::Right)
+                .key_down(0, Keycode::Down),
+        ),
+        Case::new(
+            &circular_collision_detection::LESSON,
+            200,
+            Script::new()
+                .key_down(0, Keycode::Right)
+                .key_down(0, Keycode::Down),
+        ),
+        Case::new(
+            &scrolling::LESSON,
+            400,
+            Script::new()
+                .key_down(0, Keycode::Right)
+                .key_down(0, Keycode::Down),
+        ),
+        Case::new(
+            &scrolling_backgrounds::LESSON,
+            100,
+            Script::new().key_down(0, Keycode::Right),
+        ),
+        Case::new(
+            &text_input_and_clipboard::LESSON,
+            3,
+            Script::new()
+                .text_input(0, "abc")
+                .key_down(1, Keycode::Backspace),
+        ),
+        Case::still(&texture_manipulation::LESSON),
+        Case::still(&bitmap_fonts::LESSON),
+        //Half a second in: turned 60 degrees and shrinking
+        Case::new(&render_to_texture::LESSON, 30, Script::new()),
+        Case::new(
+            &nine_slice::LESSON,
+            3,
+            Script::new()
+                .mouse_motion(0, 180, 286)
+                .mouse_button_down(1, 270, 286),
+        ),
+    ]
+}
+
+//Font and audio backends may be missing on headless machines
+fn is_unavailable(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Font { .. }
+            | Error::Mixer { .. }
+            | Error::Init {
+                subsystem: "audio",
+                ..
+            }
+    )
+}
+
+fn check(case: &Case, bless: bool) -> Outcome {
+    let name = case.lesson.name;
+    let actual = match render_lesson(case.lesson, case.frames, &case.script) {
+        Ok(actual) => actual,
+        Err(e) if is_unavailable(&e) => return Err(Failure::Skipped(e.to_string())),
+        Err(e) => return Err(e.into()),
+    };
+
+    let golden = Path::new("tests/golden").join(format!("{name}.png"));
+    if bless {
+        actual.save_png(&golden)?;
+        println!("recorded {}", golden.display());
+        return Ok(());
+    }
+    if !golden.exists() {
+        return Err(Failure::Failed(
+            "no golden, run with LAZYFOO_BLESS=1 to record one".to_string(),
+        ));
+    }
+
+    let expected = lazyfoo::harness::Snapshot::load_png(&golden)?;
+    let mismatched = actual.count_mismatches(&expected, CHANNEL_TOLERANCE);
+    if mismatched <= MAX_MISMATCHED_PIXELS {
+        return Ok(());
+    }
+
+    let diff_dir = Path::new("target/golden-diff");
+    actual.save_png(diff_dir.join(format!("{name}.actual.png")))?;
+    actual
+        .diff(&expected, CHANNEL_TOLERANCE)
+        .save_png(diff_dir.join(format!("{name}.diff.png")))?;
+    Err(Failure::Failed(format!(
+        "{mismatched} pixels differ, see target/golden-diff"
+    )))
+}
+
+fn main() -> ExitCode {
+    common::setup();
+    let bless = env::var_os("LAZYFOO_BLESS").is_some_and(|v| v == "1");
+
+    //Lessons without a case are listed too, so they fail by name
+    let cases = cases();
+    let checks: Vec<(&str, Option<&Case>)> = lessons::ALL
+        .iter()
+        .map(|info| info.name)
+        .filter(|name| !EXEMPT.contains(name))
+        .map(|name| (name, cases.iter().find(|case| case.lesson.name == name)))
+        .collect();
+    common::run("golden", &checks, |_, case| match case {
+        Some(case) => check(case, bless),
+        None => Err(Failure::Failed(
+            "no case, add one or list it in EXEMPT".to_string(),
+        )),
+    })
+}
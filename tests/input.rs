@@ -0,0 +1,184 @@
+use lazyfoo::input::{Binding, DEFAULT_BINDINGS};
+use lazyfoo::{Bindings, Input};
+use sdl2::controller::Button;
+use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::{MouseButton, MouseState};
+
+const BINDINGS: &str = r#"(
+    actions: {
+        "jump": [Key("Space"), Key("W"), Button("a")],
+        "fire": [Mouse("Left")],
+        "left": [Key("Left")],
+        "right": [Key("Right")],
+    },
+    axes: {
+        "move_x": (negative: "left", positive: "right"),
+    },
+)"#;
+
+fn input() -> Input {
+    Input::new(Bindings::parse(BINDINGS).unwrap())
+}
+
+fn key(keycode: Keycode, down: bool) -> Event {
+    if down {
+        Event::KeyDown {
+            timestamp: 0,
+            window_id: 0,
+            keycode: Some(keycode),
+            scancode: None,
+            keymod: Mod::NOMOD,
+            repeat: false,
+        }
+    } else {
+        Event::KeyUp {
+            timestamp: 0,
+            window_id: 0,
+            keycode: Some(keycode),
+            scancode: None,
+            keymod: Mod::NOMOD,
+            repeat: false,
+        }
+    }
+}
+
+#[test]
+fn parses_every_binding_kind() {
+    let bindings = Bindings::parse(BINDINGS).unwrap();
+    assert_eq!(
+        bindings.actions["jump"],
+        [
+            Binding::Key(Keycode::Space),
+            Binding::Key(Keycode::W),
+            Binding::Button(Button::A)
+        ]
+    );
+    assert_eq!(
+        bindings.actions["fire"],
+        [Binding::Mouse(MouseButton::Left)]
+    );
+}
+
+#[test]
+fn rejects_unknown_names() {
+    for text in [
+        r#"(actions: {"jump": [Key("NotAKey")]})"#,
+        r#"(actions: {"jump": [Mouse("Thumb")]})"#,
+        r#"(actions: {"jump": [Button("start button")]})"#,
+    ] {
+        assert!(Bindings::parse(text).is_err(), "{text} parsed");
+    }
+}
+
+#[test]
+fn names_the_keys_bound_to_an_action() {
+    let bindings = Bindings::parse(BINDINGS).unwrap();
+    assert_eq!(bindings.key_names("jump"), "Space/W");
+    assert_eq!(bindings.key_names("fire"), "");
+    assert_eq!(bindings.key_names("nothing"), "");
+}
+
+#[test]
+fn default_bindings_load() {
+    let bindings = Bindings::load(DEFAULT_BINDINGS).unwrap();
+    for axis in bindings.axes.values() {
+        assert!(bindings.actions.contains_key(&axis.negative));
+        assert!(bindings.actions.contains_key(&axis.positive));
+    }
+}
+
+#[test]
+fn any_bound_input_presses_the_action() {
+    let input = input();
+    input.handle_event(&key(Keycode::Space, true));
+    input.handle_event(&key(Keycode::W, true));
+    input.handle_event(&key(Keycode::Space, false));
+    assert!(input.pressed("jump"));
+
+    input.handle_event(&key(Keycode::W, false));
+    assert!(!input.pressed("jump"));
+}
+
+#[test]
+fn just_pressed_lasts_one_frame() {
+    let input = input();
+    input.handle_event(&key(Keycode::Space, true));
+    assert!(input.just_pressed("jump"));
+    input.end_frame();
+    assert!(input.pressed("jump"));
+    assert!(!input.just_pressed("jump"));
+}
+
+#[test]
+fn taps_within_a_frame_are_not_lost() {
+    let input = input();
+    input.handle_event(&Event::MouseButtonDown {
+        timestamp: 0,
+        window_id: 0,
+        which: 0,
+        mouse_btn: MouseButton::Left,
+        clicks: 1,
+        x: 0,
+        y: 0,
+    });
+    input.handle_event(&Event::MouseButtonUp {
+        timestamp: 0,
+        window_id: 0,
+        which: 0,
+        mouse_btn: MouseButton::Left,
+        clicks: 1,
+        x: 0,
+        y: 0,
+    });
+    assert!(input.just_pressed("fire"));
+    assert!(!input.pressed("fire"));
+}
+
+#[test]
+fn controller_buttons_press_actions() {
+    let input = input();
+    input.handle_event(&Event::ControllerButtonDown {
+        timestamp: 0,
+        which: 0,
+        button: Button::A,
+    });
+    assert!(input.pressed("jump"));
+}
+
+#[test]
+fn axis_combines_both_directions() {
+    let input = input();
+    assert_eq!(input.axis("move_x"), 0.0);
+    input.handle_event(&key(Keycode::Left, true));
+    assert_eq!(input.axis("move_x"), -1.0);
+    input.handle_event(&key(Keycode::Right, true));
+    assert_eq!(input.axis("move_x"), 0.0);
+    input.handle_event(&key(Keycode::Left, false));
+    assert_eq!(input.axis("move_x"), 1.0);
+}
+
+#[test]
+fn unknown_names_read_as_released() {
+    let input = input();
+    input.handle_event(&key(Keycode::Space, true));
+    assert!(!input.pressed("jum"));
+    assert!(!input.just_pressed("jum"));
+    assert_eq!(input.axis("move_z"), 0.0);
+}
+
+#[test]
+fn ignores_unrelated_events() {
+    let input = input();
+    input.handle_event(&Event::MouseMotion {
+        timestamp: 0,
+        window_id: 0,
+        which: 0,
+        mousestate: MouseState::from_sdl_state(0),
+        x: 1,
+        y: 1,
+        xrel: 1,
+        yrel: 1,
+    });
+    assert!(!input.pressed("jump"));
+}
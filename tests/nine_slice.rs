@@ -0,0 +1,89 @@
+use lazyfoo::nine_slice::{Fill, Insets, Slices};
+use sdl2::rect::Rect;
+
+//A 12x12 image with 4 pixel borders, so every slice is 4x4
+fn source() -> Rect {
+    Rect::new(0, 0, 12, 12)
+}
+
+fn covered_area(pieces: &[(Rect, Rect)]) -> u32 {
+    pieces.iter().map(|(_, to)| to.width() * to.height()).sum()
+}
+
+#[test]
+fn stretching_keeps_corners_and_fills_between() {
+    let slices = Slices::new(Insets::uniform(4));
+    let pieces = slices.pieces(source(), Rect::new(10, 20, 100, 50));
+    assert_eq!(pieces.len(), 9);
+    assert_eq!(
+        pieces[0],
+        (Rect::new(0, 0, 4, 4), Rect::new(10, 20, 4, 4)),
+        "top left corner"
+    );
+    assert_eq!(
+        pieces[4],
+        (Rect::new(4, 4, 4, 4), Rect::new(14, 24, 92, 42)),
+        "center"
+    );
+    assert_eq!(
+        pieces[8],
+        (Rect::new(8, 8, 4, 4), Rect::new(106, 66, 4, 4)),
+        "bottom right corner"
+    );
+    assert_eq!(covered_area(&pieces), 100 * 50);
+}
+
+#[test]
+fn tiling_repeats_and_cuts_the_last_copy_short() {
+    let slices = Slices::new(Insets::uniform(4))
+        .with_edges(Fill::Tile)
+        .with_center(Fill::Tile);
+    let pieces = slices.pieces(source(), Rect::new(0, 0, 18, 12));
+    //The top edge spans 10 pixels: two whole copies and half of one
+    let top: Vec<_> = pieces
+        .iter()
+        .filter(|(_, to)| to.y() == 0 && to.x() >= 4 && to.x() < 14)
+        .copied()
+        .collect();
+    assert_eq!(
+        top,
+        [
+            (Rect::new(4, 0, 4, 4), Rect::new(4, 0, 4, 4)),
+            (Rect::new(4, 0, 4, 4), Rect::new(8, 0, 4, 4)),
+            (Rect::new(4, 0, 2, 4), Rect::new(12, 0, 2, 4)),
+        ]
+    );
+    assert!(pieces.iter().all(|(from, to)| from.size() == to.size()));
+    assert_eq!(covered_area(&pieces), 18 * 12);
+}
+
+#[test]
+fn insets_are_measured_from_the_clip() {
+    let slices = Slices::new(Insets::new(1, 2, 3, 4));
+    let pieces = slices.pieces(Rect::new(32, 16, 10, 10), Rect::new(0, 0, 20, 20));
+    assert_eq!(pieces[0], (Rect::new(32, 16, 1, 2), Rect::new(0, 0, 1, 2)));
+    assert_eq!(
+        pieces[8],
+        (Rect::new(39, 22, 3, 4), Rect::new(17, 16, 3, 4))
+    );
+    assert_eq!(slices.fit(10, 10), (14, 16));
+}
+
+#[test]
+fn corners_shrink_when_there_is_no_room() {
+    let slices = Slices::new(Insets::new(4, 4, 12, 4));
+    let pieces = slices.pieces(Rect::new(0, 0, 20, 12), Rect::new(0, 0, 8, 12));
+    //No room for the middle column, and the corners split the width 1:3
+    let widths: Vec<_> = pieces.iter().map(|(_, to)| to.width()).collect();
+    assert_eq!(widths, [2, 6, 2, 6, 2, 6]);
+    assert_eq!(covered_area(&pieces), 8 * 12);
+}
+
+#[test]
+fn oversized_insets_are_cut_to_the_source() {
+    let slices = Slices::new(Insets::uniform(10));
+    let pieces = slices.pieces(source(), Rect::new(0, 0, 40, 40));
+    //Only the corners are left, 10 and 2 pixels each way
+    assert_eq!(pieces.len(), 4);
+    assert!(pieces.iter().all(|(from, to)| from.size() == to.size()));
+}
@@ -0,0 +1,91 @@
+use lazyfoo::pixels::{Pixels, TRANSPARENT};
+use sdl2::pixels::{Color, PixelFormatEnum};
+use sdl2::surface::Surface;
+
+const RED: Color = Color::RGBA(255, 0, 0, 255);
+const BLUE: Color = Color::RGBA(0, 0, 255, 255);
+
+//A 5x5 red image split by a blue column at x = 2
+fn split() -> Pixels {
+    let mut pixels = Pixels::new(5, 5, RED);
+    for y in 0..5 {
+        pixels.set(2, y, BLUE);
+    }
+    pixels
+}
+
+#[test]
+fn gets_and_sets_pixels_as_rgba8888() {
+    let mut pixels = Pixels::new(3, 2, TRANSPARENT);
+    pixels.set(2, 1, Color::RGBA(0x12, 0x34, 0x56, 0x78));
+    assert_eq!(pixels.get(2, 1), Some(Color::RGBA(0x12, 0x34, 0x56, 0x78)));
+    assert_eq!(pixels.raw()[5], 0x12345678);
+    assert_eq!(pixels.get(0, 0), Some(TRANSPARENT));
+
+    //Outside the image nothing is read or written
+    pixels.set(3, 0, RED);
+    pixels.set(-1, 0, RED);
+    assert_eq!(pixels.get(3, 0), None);
+    assert_eq!(pixels.get(0, -1), None);
+    assert!(!pixels.raw().contains(&0xff0000ff));
+}
+
+#[test]
+fn edits_alpha_alone() {
+    let mut pixels = Pixels::new(2, 1, RED);
+    pixels.set_alpha(1, 0, 0x40);
+    assert_eq!(pixels.get(1, 0), Some(Color::RGBA(255, 0, 0, 0x40)));
+    assert_eq!(pixels.get(0, 0), Some(RED));
+}
+
+#[test]
+fn replaces_exact_colors() {
+    let mut pixels = split();
+    pixels.set(0, 0, Color::RGBA(0, 0, 255, 254));
+    assert_eq!(pixels.replace_color(BLUE, TRANSPARENT), 5);
+    assert_eq!(pixels.get(2, 3), Some(TRANSPARENT));
+    assert_eq!(pixels.get(0, 0), Some(Color::RGBA(0, 0, 255, 254)));
+    assert_eq!(pixels.replace_color(BLUE, RED), 0);
+}
+
+#[test]
+fn flood_fills_only_the_connected_area() {
+    let mut pixels = split();
+    assert_eq!(pixels.flood_fill(0, 4, TRANSPARENT), 10);
+    assert_eq!(pixels.get(1, 0), Some(TRANSPARENT));
+    assert_eq!(pixels.get(3, 0), Some(RED));
+    assert_eq!(pixels.get(2, 2), Some(BLUE));
+
+    //A diagonal gap doesn't connect areas
+    let mut pixels = Pixels::new(2, 2, RED);
+    pixels.set(1, 0, BLUE);
+    pixels.set(0, 1, BLUE);
+    assert_eq!(pixels.flood_fill(0, 0, BLUE), 1);
+    assert_eq!(pixels.get(1, 1), Some(RED));
+
+    assert_eq!(pixels.flood_fill(0, 0, BLUE), 0);
+    assert_eq!(pixels.flood_fill(5, 5, BLUE), 0);
+}
+
+#[test]
+fn maps_every_pixel_with_its_position() {
+    let mut pixels = Pixels::new(4, 2, RED);
+    pixels.map(|x, y, color| Color::RGBA(color.r, x as u8, y as u8, 255 - 60 * x as u8));
+    assert_eq!(pixels.get(3, 1), Some(Color::RGBA(255, 3, 1, 75)));
+    assert_eq!(pixels.get(0, 1), Some(Color::RGBA(255, 0, 1, 255)));
+}
+
+#[test]
+fn converts_surfaces() {
+    let mut surface = Surface::new(3, 2, PixelFormatEnum::RGB24).unwrap();
+    surface.fill_rect(None, Color::RGB(0, 255, 255)).unwrap();
+    surface
+        .fill_rect(sdl2::rect::Rect::new(1, 1, 1, 1), Color::RGB(10, 20, 30))
+        .unwrap();
+
+    let pixels = Pixels::from_surface(&surface).unwrap();
+    assert_eq!((pixels.width(), pixels.height()), (3, 2));
+    assert_eq!(pixels.get(0, 0), Some(Color::RGBA(0, 255, 255, 255)));
+    assert_eq!(pixels.get(1, 1), Some(Color::RGBA(10, 20, 30, 255)));
+    assert_eq!(pixels.to_bytes().len(), 3 * 2 * 4);
+}
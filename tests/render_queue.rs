@@ -0,0 +1,167 @@
+//! Flushes `RenderQueue`s into a software canvas on SDL's dummy video driver
+//! and checks what ended up on top and what the stats counted.
+
+mod common;
+
+use common::{cases, expect, Outcome};
+use lazyfoo::pixels::Pixels;
+use lazyfoo::{BitmapFont, Depth, LTexture, RenderQueue, RenderStats, Result};
+use sdl2::pixels::{Color, PixelFormatEnum};
+use sdl2::rect::Rect;
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::video::WindowContext;
+use std::process::ExitCode;
+
+const RED: Color = Color::RGB(255, 0, 0);
+const BLUE: Color = Color::RGB(0, 0, 255);
+
+struct Target<'a> {
+    canvas: &'a mut WindowCanvas,
+    texture_creator: &'a TextureCreator<WindowContext>,
+}
+
+impl<'a> Target<'a> {
+    fn texture(&self, color: Color) -> Result<LTexture<'a>> {
+        LTexture::from_pixels(self.texture_creator, Pixels::new(4, 4, color))
+    }
+
+    fn flush(&mut self, queue: &mut RenderQueue) -> Result<RenderStats> {
+        self.canvas.set_draw_color(Color::RGB(255, 255, 255));
+        self.canvas.clear();
+        queue.flush(self.canvas)
+    }
+
+    fn color_at(&self, x: i32, y: i32) -> Result<Color, String> {
+        let bytes = self
+            .canvas
+            .read_pixels(Rect::new(x, y, 1, 1), PixelFormatEnum::RGBA32)?;
+        Ok(Color::RGB(bytes[0], bytes[1], bytes[2]))
+    }
+}
+
+fn later_layers_are_drawn_on_top(target: &mut Target) -> Outcome {
+    let mut queue = RenderQueue::new();
+    queue.fill_rect(Depth::layer(1), Rect::new(0, 0, 10, 10), RED);
+    queue.fill_rect(Depth::layer(0), Rect::new(0, 0, 10, 10), BLUE);
+    target.flush(&mut queue)?;
+    expect(target.color_at(5, 5)? == RED, "layer 1 covers layer 0")?;
+    expect(queue.is_empty(), "flushing empties the queue")
+}
+
+fn lower_sprites_are_drawn_over_higher_ones(target: &mut Target) -> Outcome {
+    let red = target.texture(RED)?;
+    let blue = target.texture(BLUE)?;
+    let mut queue = RenderQueue::new();
+    //Blue is further down the screen, so in front even though it came first
+    let front = Rect::new(2, 2, 4, 4);
+    let back = Rect::new(0, 0, 4, 4);
+    queue.sprite_to(Depth::y_sorted(0, front), &blue, None, front);
+    queue.sprite_to(Depth::y_sorted(0, back), &red, None, back);
+    target.flush(&mut queue)?;
+    expect(
+        target.color_at(3, 3)? == BLUE,
+        "the lower sprite is in front",
+    )?;
+    expect(
+        target.color_at(1, 1)? == RED,
+        "the higher sprite still shows",
+    )
+}
+
+fn groups_copies_by_texture(target: &mut Target) -> Outcome {
+    let red = target.texture(RED)?;
+    let blue = target.texture(BLUE)?;
+    let mut queue = RenderQueue::new();
+    for (i, texture) in [&red, &blue, &red, &blue].into_iter().enumerate() {
+        queue.sprite(Depth::layer(0), texture, i as i32 * 4, 0, None);
+    }
+    let stats = target.flush(&mut queue)?;
+    expect(
+        stats
+            == RenderStats {
+                commands: 4,
+                draw_calls: 2,
+                texture_switches: 1,
+            },
+        &format!("one depth is drawn a texture at a time, got {stats:?}"),
+    )?;
+
+    for (i, texture) in [&red, &blue, &red, &blue].into_iter().enumerate() {
+        queue.sprite(Depth::new(0, i as i32), texture, i as i32 * 4, 0, None);
+    }
+    let stats = target.flush(&mut queue)?;
+    expect(
+        stats.draw_calls == 4 && stats.texture_switches == 3,
+        &format!("different depths keep their order, got {stats:?}"),
+    )
+}
+
+//Which overlapping copy ends up on top follows the queue, not the textures' addresses
+fn textures_batch_in_the_order_they_were_queued(target: &mut Target) -> Outcome {
+    let red = target.texture(RED)?;
+    let blue = target.texture(BLUE)?;
+    let mut queue = RenderQueue::new();
+    for (first, second, on_top) in [(&red, &blue, BLUE), (&blue, &red, RED)] {
+        //The third copy joins the first texture's batch, under the second
+        queue.sprite(Depth::layer(0), first, 0, 0, None);
+        queue.sprite(Depth::layer(0), second, 0, 0, None);
+        queue.sprite(Depth::layer(0), first, 0, 0, None);
+        let stats = target.flush(&mut queue)?;
+        expect(
+            stats.draw_calls == 2,
+            &format!("copies are grouped by texture, got {stats:?}"),
+        )?;
+        expect(
+            target.color_at(1, 1)? == on_top,
+            "the texture queued second is drawn second",
+        )?;
+    }
+    Ok(())
+}
+
+fn primitives_split_runs_of_copies(target: &mut Target) -> Outcome {
+    let red = target.texture(RED)?;
+    let mut queue = RenderQueue::new();
+    queue.sprite(Depth::new(0, 0), &red, 0, 0, None);
+    queue.draw_rect(Depth::new(0, 1), Rect::new(0, 0, 8, 8), BLUE);
+    queue.sprite(Depth::new(0, 2), &red, 4, 4, None);
+    let stats = target.flush(&mut queue)?;
+    expect(
+        stats.draw_calls == 3 && stats.texture_switches == 0,
+        &format!("got {stats:?}"),
+    )
+}
+
+fn text_is_queued_a_glyph_at_a_time(target: &mut Target) -> Outcome {
+    let font = BitmapFont::load(target.texture_creator, "resources/lazyfont.png")?;
+    let mut queue = RenderQueue::new();
+    queue.text(Depth::layer(1), &font, 0, 0, "AB A");
+    expect(queue.len() == 3, "spaces are not drawn")?;
+    queue.fill_rect(Depth::layer(0), Rect::new(0, 0, 100, 40), BLUE);
+    let stats = target.flush(&mut queue)?;
+    expect(
+        stats.draw_calls == 2 && stats.texture_switches == 0,
+        &format!("got {stats:?}"),
+    )
+}
+
+type Case = fn(&mut Target) -> Outcome;
+
+fn main() -> ExitCode {
+    let mut sdl = common::Sdl::start("render queue");
+    let texture_creator = sdl.canvas.texture_creator();
+    let mut target = Target {
+        canvas: &mut sdl.canvas,
+        texture_creator: &texture_creator,
+    };
+
+    let cases: [(&str, Case); 6] = cases![
+        later_layers_are_drawn_on_top,
+        lower_sprites_are_drawn_over_higher_ones,
+        groups_copies_by_texture,
+        textures_batch_in_the_order_they_were_queued,
+        primitives_split_runs_of_copies,
+        text_is_queued_a_glyph_at_a_time,
+    ];
+    common::run("render_queue", &cases, |_, case| case(&mut target))
+}
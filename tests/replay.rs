@@ -0,0 +1,257 @@
+use lazyfoo::collision::Shape;
+use lazyfoo::harness::Script;
+use lazyfoo::input::DEFAULT_BINDINGS;
+use lazyfoo::replay::{is_recordable, Checksum, Frame, Recording};
+use lazyfoo::timestep::FixedTimestep;
+use lazyfoo::{Bindings, Dot, Error, Input};
+use sdl2::controller::{Axis, Button};
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::{MouseButton, MouseState, MouseWheelDirection};
+use sdl2::rect::Rect;
+use std::hash::Hasher;
+use std::slice;
+use std::time::Duration;
+
+fn every_recorded_kind() -> Vec<Event> {
+    vec![
+        Event::Quit { timestamp: 0 },
+        Event::KeyDown {
+            timestamp: 0,
+            window_id: 0,
+            keycode: Some(Keycode::Right),
+            scancode: None,
+            keymod: Mod::LSHIFTMOD,
+            repeat: false,
+        },
+        Event::KeyUp {
+            timestamp: 0,
+            window_id: 0,
+            keycode: Some(Keycode::Right),
+            scancode: None,
+            keymod: Mod::NOMOD,
+            repeat: true,
+        },
+        Event::TextInput {
+            timestamp: 0,
+            window_id: 0,
+            text: "héllo".to_string(),
+        },
+        Event::MouseMotion {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            mousestate: MouseState::from_sdl_state(1),
+            x: 320,
+            y: -4,
+            xrel: -3,
+            yrel: 200,
+        },
+        Event::MouseButtonDown {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            mouse_btn: MouseButton::Right,
+            clicks: 2,
+            x: 10,
+            y: 20,
+        },
+        Event::MouseButtonUp {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            mouse_btn: MouseButton::Right,
+            clicks: 2,
+            x: 10,
+            y: 20,
+        },
+        Event::MouseWheel {
+            timestamp: 0,
+            window_id: 0,
+            which: 0,
+            x: 0,
+            y: -1,
+            direction: MouseWheelDirection::Flipped,
+            precise_x: 0.0,
+            precise_y: -0.75,
+            mouse_x: 100,
+            mouse_y: 50,
+        },
+        Event::ControllerAxisMotion {
+            timestamp: 0,
+            which: 3,
+            axis: Axis::TriggerRight,
+            value: i16::MIN,
+        },
+        Event::ControllerButtonDown {
+            timestamp: 0,
+            which: 3,
+            button: Button::Touchpad,
+        },
+        Event::ControllerButtonUp {
+            timestamp: 0,
+            which: 3,
+            button: Button::A,
+        },
+        Event::ControllerDeviceAdded {
+            timestamp: 0,
+            which: 0,
+        },
+        Event::ControllerDeviceRemoved {
+            timestamp: 0,
+            which: 3,
+        },
+    ]
+}
+
+#[test]
+fn recordings_round_trip() {
+    let recording = Recording {
+        lesson: "box_collision_detection".to_string(),
+        frames: vec![
+            Frame {
+                elapsed: Duration::from_nanos(16_666_667),
+                events: every_recorded_kind(),
+                checksum: 0x0123_4567_89ab_cdef,
+            },
+            Frame {
+                elapsed: Duration::from_secs(3),
+                events: Vec::new(),
+                checksum: u64::MAX,
+            },
+        ],
+    };
+    assert_eq!(Recording::decode(&recording.encode()).unwrap(), recording);
+}
+
+#[test]
+fn drops_events_lessons_cannot_see() {
+    let window = Event::Window {
+        timestamp: 0,
+        window_id: 1,
+        win_event: WindowEvent::FocusGained,
+    };
+    assert!(!is_recordable(&window));
+    assert!(every_recorded_kind().iter().all(is_recordable));
+
+    let recording = Recording {
+        lesson: "motion".to_string(),
+        frames: vec![Frame {
+            elapsed: Duration::ZERO,
+            events: vec![window, Event::Quit { timestamp: 0 }],
+            checksum: 0,
+        }],
+    };
+    let decoded = Recording::decode(&recording.encode()).unwrap();
+    assert_eq!(decoded.frames[0].events, [Event::Quit { timestamp: 0 }]);
+}
+
+#[test]
+fn rejects_damaged_recordings() {
+    let bytes = Recording {
+        lesson: "motion".to_string(),
+        frames: vec![Frame {
+            elapsed: Duration::from_millis(16),
+            events: every_recorded_kind(),
+            checksum: 7,
+        }],
+    }
+    .encode();
+
+    assert!(matches!(
+        Recording::decode(b"not a recording"),
+        Err(Error::BadRecording { .. })
+    ));
+    for len in 0..bytes.len() {
+        //Cutting at a frame boundary leaves a valid, shorter recording
+        if let Ok(recording) = Recording::decode(&bytes[..len]) {
+            assert!(
+                recording.frames.is_empty(),
+                "decoded a partial frame at {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn checksum_is_stable_fnv1a() {
+    let mut checksum = Checksum::default();
+    checksum.write(b"a");
+    assert_eq!(checksum.finish(), 0xaf63_dc4c_8601_ec8c);
+}
+
+//Runs a dot through `frames` the way the lesson runner does and returns the per-frame checksums
+fn play(frames: &[Frame]) -> Vec<u64> {
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS).unwrap());
+    let mut timestep = FixedTimestep::default();
+    let mut dot = Dot::new(0.0, 0.0, 240.0);
+    let wall = Shape::Aabb(Rect::new(300, 40, 40, 400));
+
+    frames
+        .iter()
+        .map(|frame| {
+            frame.events.iter().for_each(|e| input.handle_event(e));
+            for _ in 0..timestep.advance(frame.elapsed) {
+                dot.handle_input(&input);
+                dot.move_position_with(
+                    Rect::new(0, 0, 640, 480),
+                    timestep.step(),
+                    slice::from_ref(&wall),
+                    |dot| Shape::Aabb(dot.collider()),
+                );
+            }
+            input.end_frame();
+
+            let mut checksum = Checksum::default();
+            dot.hash_state(&mut checksum);
+            checksum.finish()
+        })
+        .collect()
+}
+
+//Uneven frame times and key changes mid-run, like a real session
+fn session() -> Vec<Frame> {
+    let script = Script::new()
+        .key_down(3, Keycode::Right)
+        .key_down(90, Keycode::Down)
+        .key_up(150, Keycode::Right)
+        .key_down(150, Keycode::Left)
+        .key_up(220, Keycode::Down);
+    (0..300u32)
+        .map(|i| Frame {
+            elapsed: Duration::from_micros(9_000 + (u64::from(i) * 7919) % 15_000),
+            events: script.events_at(i).collect(),
+            checksum: 0,
+        })
+        .collect()
+}
+
+#[test]
+fn replay_reproduces_every_frame() {
+    let mut frames = session();
+    let recorded = play(&frames);
+    for (frame, checksum) in frames.iter_mut().zip(&recorded) {
+        frame.checksum = *checksum;
+    }
+    let recording = Recording {
+        lesson: "box_collision_detection".to_string(),
+        frames,
+    };
+
+    let replayed = Recording::decode(&recording.encode()).unwrap();
+    let checksums = play(&replayed.frames);
+    assert_eq!(checksums, recorded);
+    //The dot does move, so matching checksums mean something
+    assert!(recorded.windows(2).any(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn checksums_catch_divergence() {
+    let recorded = play(&session());
+    let mut frames = session();
+    frames[120].elapsed += Duration::from_millis(20);
+    let replayed = play(&frames);
+
+    let diverged = recorded.iter().zip(&replayed).position(|(a, b)| a != b);
+    assert_eq!(diverged, Some(120));
+}
@@ -0,0 +1,158 @@
+use lazyfoo::sheet::{Direction, Sheet, DEFAULT_FRAME_DURATION};
+use lazyfoo::{Error, Playback};
+use sdl2::rect::{Point, Rect};
+use std::path::Path;
+use std::time::Duration;
+
+//TexturePacker's hash format, with names out of alphabetical order
+const HASH: &str = r#"{
+    "frames": {
+        "walk_2": { "frame": {"x":10,"y":0,"w":10,"h":20}, "rotated": false, "trimmed": true,
+                    "spriteSourceSize": {"x":3,"y":1,"w":10,"h":20}, "sourceSize": {"w":16,"h":22} },
+        "walk_10": { "frame": {"x":0,"y":0,"w":10,"h":20}, "rotated": false, "trimmed": false,
+                     "spriteSourceSize": {"x":0,"y":0,"w":10,"h":20}, "sourceSize": {"w":10,"h":20} }
+    },
+    "meta": { "image": "walk.png", "size": {"w":20,"h":20}, "scale": "1" }
+}"#;
+
+const ARRAY: &str = r#"{
+    "frames": [
+        { "filename": "idle", "frame": {"x":0,"y":0,"w":8,"h":8} },
+        { "filename": "jump", "frame": {"x":8,"y":0,"w":8,"h":12} }
+    ],
+    "meta": { "image": "hero.png" }
+}"#;
+
+//Aseprite's array export with a tag for each direction
+const ASEPRITE: &str = r##"{
+    "frames": [
+        { "filename": "hero 0.aseprite", "frame": {"x":0,"y":0,"w":16,"h":16}, "duration": 100 },
+        { "filename": "hero 1.aseprite", "frame": {"x":16,"y":0,"w":16,"h":16}, "duration": 50 },
+        { "filename": "hero 2.aseprite", "frame": {"x":32,"y":0,"w":16,"h":16}, "duration": 200 },
+        { "filename": "hero 3.aseprite", "frame": {"x":48,"y":0,"w":16,"h":16}, "duration": 100 }
+    ],
+    "meta": {
+        "app": "https://www.aseprite.org/",
+        "image": "hero.png",
+        "frameTags": [
+            { "name": "run", "from": 0, "to": 2, "direction": "forward", "color": "#000000ff" },
+            { "name": "back", "from": 1, "to": 3, "direction": "reverse" },
+            { "name": "bob", "from": 0, "to": 2, "direction": "pingpong" },
+            { "name": "bob_back", "from": 0, "to": 2, "direction": "pingpong_reverse" },
+            { "name": "land", "from": 2, "to": 3, "direction": "forward", "repeat": "1" }
+        ]
+    }
+}"##;
+
+fn clips(sheet: &Sheet, tag: &str, steps: usize) -> Vec<i32> {
+    let mut animation = sheet.animation(tag).unwrap();
+    let mut seen = vec![animation.current_clip().x()];
+    for _ in 0..steps {
+        animation.advance(Duration::from_millis(50));
+        seen.push(animation.current_clip().x());
+    }
+    seen
+}
+
+#[test]
+fn reads_the_hash_format_in_file_order() {
+    let sheet = Sheet::parse(HASH).unwrap();
+    assert_eq!(sheet.image(), Path::new("walk.png"));
+    let names: Vec<_> = sheet.frames().iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["walk_2", "walk_10"]);
+
+    let trimmed = sheet.frame("walk_2").unwrap();
+    assert_eq!(trimmed.clip, Rect::new(10, 0, 10, 20));
+    assert_eq!(trimmed.offset, Point::new(3, 1));
+    assert_eq!(trimmed.duration, DEFAULT_FRAME_DURATION);
+    assert!(sheet.tags().is_empty());
+}
+
+#[test]
+fn reads_the_array_format() {
+    let sheet = Sheet::parse(ARRAY).unwrap();
+    assert_eq!(sheet.frame("jump").unwrap().clip, Rect::new(8, 0, 8, 12));
+    assert_eq!(sheet.frame("idle").unwrap().offset, Point::new(0, 0));
+    assert!(sheet.frame("fall").is_none());
+
+    let animation = sheet.all_frames(Playback::Once);
+    assert_eq!(animation.current_clip(), Rect::new(0, 0, 8, 8));
+    assert_eq!(animation.playback(), Playback::Once);
+}
+
+#[test]
+fn reads_aseprite_tags_and_durations() {
+    let sheet = Sheet::parse(ASEPRITE).unwrap();
+    assert_eq!(sheet.frames()[1].duration, Duration::from_millis(50));
+    assert_eq!(sheet.tags().len(), 5);
+    assert_eq!(sheet.tags()[2].direction, Direction::PingPong);
+    assert_eq!(sheet.tags()[4].repeat, Some(1));
+
+    //Each frame keeps its own duration: 100, 50, 200
+    assert_eq!(clips(&sheet, "run", 8), [0, 0, 16, 32, 32, 32, 32, 0, 0]);
+    assert_eq!(clips(&sheet, "back", 2), [48, 48, 32]);
+    assert_eq!(
+        sheet.animation("bob").unwrap().playback(),
+        Playback::PingPong
+    );
+    assert_eq!(clips(&sheet, "bob_back", 4), [32, 32, 32, 32, 16]);
+
+    let mut land = sheet.animation("land").unwrap();
+    assert_eq!(land.playback(), Playback::Once);
+    land.advance(Duration::from_secs(1));
+    assert!(land.is_finished());
+    assert_eq!(land.current_clip().x(), 48);
+}
+
+#[test]
+fn unknown_tags_are_errors() {
+    let sheet = Sheet::parse(ASEPRITE).unwrap();
+    assert!(matches!(
+        sheet.animation("fly"),
+        Err(Error::MissingAnimation { name }) if name == "fly"
+    ));
+}
+
+#[test]
+fn rejects_sheets_it_cannot_draw() {
+    let rotated = ARRAY.replacen(
+        r#""frame": {"x":0"#,
+        r#""rotated": true, "frame": {"x":0"#,
+        1,
+    );
+    let error = Sheet::parse(&rotated).unwrap_err().to_string();
+    assert!(error.contains("rotated"), "{error}");
+
+    let past_end = ASEPRITE.replace(r#""from": 2, "to": 3"#, r#""from": 2, "to": 4"#);
+    let error = Sheet::parse(&past_end).unwrap_err().to_string();
+    assert!(error.contains("\"land\""), "{error}");
+
+    let empty = r#"{ "frames": [], "meta": { "image": "none.png" } }"#;
+    assert!(Sheet::parse(empty).is_err());
+    assert!(Sheet::parse(r#"{ "frames": [] }"#).is_err());
+}
+
+#[test]
+fn loads_the_lesson_sheets() {
+    let foo = Sheet::load("resources/foo2.json").unwrap();
+    assert_eq!(foo.image(), Path::new("resources/foo2.png"));
+    assert_eq!(foo.frames().len(), 4);
+    assert_eq!(foo.animation("walk").unwrap().playback(), Playback::Loop);
+    assert_eq!(
+        foo.animation("sway").unwrap().playback(),
+        Playback::PingPong
+    );
+    assert_eq!(foo.animation("wave").unwrap().playback(), Playback::Once);
+
+    let dots = Sheet::load("resources/dots.json").unwrap();
+    assert_eq!(dots.image(), Path::new("resources/dots.png"));
+    assert_eq!(
+        dots.frame("bottom_right").unwrap().clip,
+        Rect::new(100, 100, 100, 100)
+    );
+
+    assert!(matches!(
+        Sheet::load("resources/missing.json"),
+        Err(Error::AssetNotFound { .. })
+    ));
+}
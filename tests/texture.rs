@@ -0,0 +1,73 @@
+//! Edits streaming textures through `PixelLock` on SDL's dummy video driver
+//! and checks the edits reach what is drawn.
+
+mod common;
+
+use common::{cases, expect, Outcome};
+use lazyfoo::{LTexture, Pixels};
+use sdl2::pixels::{Color, PixelFormatEnum};
+use sdl2::rect::Rect;
+use sdl2::render::{TextureCreator, WindowCanvas};
+use sdl2::video::WindowContext;
+use std::process::ExitCode;
+
+const RED: Color = Color::RGB(255, 0, 0);
+const BLUE: Color = Color::RGB(0, 0, 255);
+
+struct Target<'a> {
+    canvas: &'a mut WindowCanvas,
+    texture_creator: &'a TextureCreator<WindowContext>,
+}
+
+impl Target<'_> {
+    //What the top left pixel of `texture` looks like once drawn
+    fn drawn(&mut self, texture: &LTexture) -> Result<Color, String> {
+        self.canvas.set_draw_color(Color::RGB(255, 255, 255));
+        self.canvas.clear();
+        texture
+            .render(self.canvas, 0, 0, None)
+            .map_err(|e| e.to_string())?;
+        let bytes = self
+            .canvas
+            .read_pixels(Rect::new(0, 0, 1, 1), PixelFormatEnum::RGBA32)?;
+        Ok(Color::RGB(bytes[0], bytes[1], bytes[2]))
+    }
+}
+
+fn unlocking_uploads_the_edits(target: &mut Target) -> Outcome {
+    let mut texture = LTexture::from_pixels(target.texture_creator, Pixels::new(4, 4, RED))?;
+    let mut pixels = texture.lock()?;
+    pixels.set(0, 0, BLUE);
+    pixels.unlock()?;
+    expect(target.drawn(&texture)? == BLUE, "the edit was drawn")
+}
+
+fn dropping_the_lock_uploads_the_edits(target: &mut Target) -> Outcome {
+    let mut texture = LTexture::from_pixels(target.texture_creator, Pixels::new(4, 4, RED))?;
+    texture.lock()?.set(0, 0, BLUE);
+    expect(
+        texture.pixels().and_then(|pixels| pixels.get(0, 0)) == Some(BLUE),
+        "the edit was kept",
+    )?;
+    expect(
+        target.drawn(&texture)? == BLUE,
+        "the texture shows the kept pixels",
+    )
+}
+
+type Case = fn(&mut Target) -> Outcome;
+
+fn main() -> ExitCode {
+    let mut sdl = common::Sdl::start("texture");
+    let texture_creator = sdl.canvas.texture_creator();
+    let mut target = Target {
+        canvas: &mut sdl.canvas,
+        texture_creator: &texture_creator,
+    };
+
+    let cases: [(&str, Case); 2] = cases![
+        unlocking_uploads_the_edits,
+        dropping_the_lock_uploads_the_edits,
+    ];
+    common::run("texture", &cases, |_, case| case(&mut target))
+}
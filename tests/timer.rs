@@ -0,0 +1,158 @@
+use lazyfoo::{Clock, FpsCounter, LTimer, ManualClock};
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn started_timer() -> (ManualClock, LTimer) {
+    let clock = ManualClock::new();
+    let mut timer = LTimer::new(clock.clone());
+    timer.start();
+    (clock, timer)
+}
+
+#[test]
+fn counts_only_while_started() {
+    let clock = ManualClock::new();
+    let mut timer = LTimer::new(clock.clone());
+    clock.advance(ms(500));
+    assert_eq!(timer.get_ticks(), Duration::ZERO);
+
+    timer.start();
+    clock.advance(ms(250));
+    assert_eq!(timer.get_ticks(), ms(250));
+
+    timer.stop();
+    assert_eq!(timer.get_ticks(), Duration::ZERO);
+    assert!(!timer.is_started());
+}
+
+#[test]
+fn pause_while_stopped_does_nothing() {
+    let clock = ManualClock::new();
+    let mut timer = LTimer::new(clock.clone());
+    timer.pause();
+    assert!(!timer.is_paused());
+
+    //Starting afterwards must not inherit a stale pause
+    timer.start();
+    clock.advance(ms(100));
+    assert_eq!(timer.get_ticks(), ms(100));
+    assert!(!timer.is_paused());
+}
+
+#[test]
+fn unpause_while_not_paused_does_nothing() {
+    let (clock, mut timer) = started_timer();
+    clock.advance(ms(300));
+    timer.unpause();
+    clock.advance(ms(200));
+    assert_eq!(timer.get_ticks(), ms(500));
+
+    let mut stopped = LTimer::new(clock.clone());
+    stopped.unpause();
+    assert!(!stopped.is_started());
+    assert_eq!(stopped.get_ticks(), Duration::ZERO);
+}
+
+#[test]
+fn pause_freezes_time_until_unpaused() {
+    let (clock, mut timer) = started_timer();
+    clock.advance(ms(100));
+    timer.pause();
+    timer.pause();
+    clock.advance(ms(1000));
+    assert!(timer.is_paused());
+    assert_eq!(timer.get_ticks(), ms(100));
+
+    timer.unpause();
+    clock.advance(ms(50));
+    assert_eq!(timer.get_ticks(), ms(150));
+}
+
+#[test]
+fn time_scale_applies_from_when_it_is_set() {
+    let (clock, mut timer) = started_timer();
+    clock.advance(ms(100));
+    timer.set_time_scale(0.5);
+    clock.advance(ms(100));
+    assert_eq!(timer.get_ticks(), ms(150));
+
+    timer.set_time_scale(2.0);
+    clock.advance(ms(100));
+    assert_eq!(timer.get_ticks(), ms(350));
+}
+
+#[test]
+fn child_follows_parent_pause_and_scale() {
+    let (clock, mut parent) = started_timer();
+    let mut child = parent.child();
+    child.start();
+
+    parent.set_time_scale(2.0);
+    clock.advance(ms(100));
+    assert_eq!(child.get_ticks(), ms(200));
+
+    parent.pause();
+    clock.advance(ms(100));
+    assert!(child.is_paused());
+    assert_eq!(child.get_ticks(), ms(200));
+
+    parent.unpause();
+    child.set_time_scale(0.5);
+    clock.advance(ms(100));
+    assert!(!child.is_paused());
+    assert_eq!(child.get_ticks(), ms(300));
+}
+
+#[test]
+fn runs_past_the_millisecond_tick_wrap() {
+    //SDL_GetTicks counts milliseconds in a u32, which wraps after about 49 days
+    let wrap = ms(u64::from(u32::MAX) + 1);
+    let clock = ManualClock::starting_at(wrap - ms(10));
+    let mut timer = LTimer::new(clock.clone());
+    timer.start();
+    for step in 1..=20 {
+        clock.advance(ms(1));
+        assert_eq!(timer.get_ticks(), ms(step), "at {:?}", clock.now());
+    }
+    assert!(clock.now() > wrap, "the clock went past the wrap");
+}
+
+#[test]
+fn fps_is_zero_before_time_passes() {
+    let clock = ManualClock::new();
+    let mut fps = FpsCounter::new(clock.clone());
+    assert_eq!(fps.average(), 0.0);
+
+    fps.count_frame();
+    assert_eq!(fps.average(), 0.0);
+}
+
+#[test]
+fn fps_discards_implausible_rates() {
+    let clock = ManualClock::new();
+    let mut fps = FpsCounter::new(clock.clone());
+    fps.count_frame();
+    fps.count_frame();
+
+    //2 frames in 1µs is 2000000 fps, right at the guard
+    clock.advance(Duration::from_micros(1));
+    assert!((fps.average() - 2000000.0).abs() < 1.0);
+
+    fps.count_frame();
+    assert_eq!(fps.average(), 0.0);
+}
+
+#[test]
+fn fps_averages_over_elapsed_time() {
+    let clock = ManualClock::new();
+    let mut fps = FpsCounter::new(clock.clone());
+    for _ in 0..120 {
+        clock.advance(Duration::from_secs(1) / 60);
+        fps.count_frame();
+    }
+    assert_eq!(fps.counted_frames(), 120);
+    assert!((fps.average() - 60.0).abs() < 0.01);
+}
@@ -0,0 +1,68 @@
+use lazyfoo::harness::Script;
+use lazyfoo::input::DEFAULT_BINDINGS;
+use lazyfoo::timestep::FixedTimestep;
+use lazyfoo::{Bindings, Dot, Input};
+use sdl2::keyboard::Keycode;
+use sdl2::rect::Rect;
+use std::time::Duration;
+
+//Holds Right for one second of frames at `fps` and returns where the dot is drawn
+fn drawn_x_after_one_second(fps: u32) -> f32 {
+    let mut timestep = FixedTimestep::new(60);
+    let mut dot = Dot::new(0.0, 0.0, 300.0);
+    let input = Input::new(Bindings::load(DEFAULT_BINDINGS).unwrap());
+    for event in Script::new().key_down(0, Keycode::Right).events_at(0) {
+        input.handle_event(&event);
+    }
+
+    let frame = Duration::from_secs(1) / fps;
+    for _ in 0..fps {
+        for _ in 0..timestep.advance(frame) {
+            dot.handle_input(&input);
+            dot.move_position(Rect::new(0, 0, 1000, 1000), timestep.step());
+        }
+    }
+    dot.position_at(timestep.alpha()).x
+}
+
+#[test]
+fn motion_does_not_depend_on_frame_rate() {
+    let at_60 = drawn_x_after_one_second(60);
+    for fps in [30, 144] {
+        let x = drawn_x_after_one_second(fps);
+        assert!(
+            (x - at_60).abs() < 0.01,
+            "{fps} fps drew x={x}, 60 fps drew x={at_60}"
+        );
+    }
+    //One tick behind the simulated 300px, as rendering interpolates from the previous tick
+    assert!((at_60 - 295.0).abs() < 0.01, "drew x={at_60}");
+}
+
+#[test]
+fn accumulates_partial_frames() {
+    let mut timestep = FixedTimestep::new(100);
+    assert_eq!(timestep.advance(Duration::from_millis(4)), 0);
+    assert!((timestep.alpha() - 0.4).abs() < 1e-4);
+    assert_eq!(timestep.advance(Duration::from_millis(7)), 1);
+    assert!((timestep.alpha() - 0.1).abs() < 1e-4);
+    assert_eq!(timestep.advance(Duration::from_millis(25)), 2);
+    assert!((timestep.alpha() - 0.6).abs() < 1e-4);
+}
+
+#[test]
+fn caps_ticks_per_frame() {
+    let mut timestep = FixedTimestep::new(100).with_max_frame_skip(3);
+    assert_eq!(timestep.advance(Duration::from_secs(2)), 3);
+    //The backlog past the cap is dropped rather than carried into later frames
+    assert_eq!(timestep.alpha(), 0.0);
+    assert_eq!(timestep.advance(Duration::from_millis(10)), 1);
+}
+
+#[test]
+fn reset_discards_accumulated_time() {
+    let mut timestep = FixedTimestep::new(100);
+    timestep.advance(Duration::from_millis(9));
+    timestep.reset();
+    assert_eq!(timestep.advance(Duration::from_millis(9)), 0);
+}